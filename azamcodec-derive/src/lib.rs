@@ -0,0 +1,375 @@
+/*!
+Derive macros for [`AzamEncode`](https://docs.rs/azamcodec/latest/azamcodec/encode/trait.AzamEncode.html) and [`AzamDecode`](https://docs.rs/azamcodec/latest/azamcodec/decode/trait.AzamDecode.html) traits of azamcodec.
+
+Use through the `derive` feature of azamcodec, instead of depending on this crate directly.
+
+```rust
+use azamcodec::{decode::AzamDecode, encode::AzamEncode};
+
+#[derive(AzamEncode, AzamDecode)]
+struct Id {
+    record_id: u32,
+    type_id: u8,
+    variant_id: u16,
+}
+```
+
+Structs are encoded as consecutive sections of their fields, in field order.
+Enums are encoded as a section of variant index, followed by consecutive sections of the variant fields.
+Variant index is encoded as an ordered section, so variants sort in declaration order.
+
+Type parameters are not bounded, instead the types of encoded fields are bounded by the derived trait, so parameters of skipped fields need no bounds.
+
+Container attributes:
+* `#[azam(crate = "path")]` - Path of azamcodec crate, when it is renamed or re-exported, defaults to `::azamcodec`.
+
+Field attributes:
+* `#[azam(skip)]` - Field is not encoded, and decoded as [`Default::default()`].
+* `#[azam(desc)]` - Field is encoded through [`std::cmp::Reverse`], so it sorts in descending order.
+ */
+
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Index, Result};
+
+/// Derive `AzamEncode` trait for structs and enums.
+#[proc_macro_derive(AzamEncode, attributes(azam))]
+pub fn derive_azam_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_encode(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derive `AzamDecode` trait for structs and enums.
+#[proc_macro_derive(AzamDecode, attributes(azam))]
+pub fn derive_azam_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_decode(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Options of a struct or enum, parsed from `#[azam(...)]` attributes.
+struct ContainerOptions {
+    /// Path of azamcodec crate.
+    krate: syn::Path,
+}
+
+impl ContainerOptions {
+    fn parse(attrs: &[syn::Attribute]) -> Result<Self> {
+        let mut options = Self {
+            krate: syn::parse_quote!(::azamcodec),
+        };
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("azam")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("crate") {
+                    options.krate = meta.value()?.parse::<syn::LitStr>()?.parse()?;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported azam attribute, expected `crate`"))
+                }
+            })?;
+        }
+        Ok(options)
+    }
+}
+
+/// Options of a field, parsed from `#[azam(...)]` attributes.
+#[derive(Default)]
+struct FieldOptions {
+    skip: bool,
+    desc: bool,
+}
+
+impl FieldOptions {
+    fn parse(attrs: &[syn::Attribute]) -> Result<Self> {
+        let mut options = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("azam")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    options.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("desc") {
+                    options.desc = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported azam attribute, expected `skip` or `desc`"))
+                }
+            })?;
+        }
+        if options.skip && options.desc {
+            return Err(Error::new(
+                Span::call_site(),
+                "azam attributes `skip` and `desc` cannot be used together",
+            ));
+        }
+        Ok(options)
+    }
+}
+
+/// Field of a struct or enum variant, with the expression to access it.
+struct Field {
+    ty: syn::Type,
+    options: FieldOptions,
+    /// Member of `self` for structs, or binding name for enum variants.
+    member: syn::Member,
+    binding: syn::Ident,
+}
+
+fn parse_fields(fields: &Fields) -> Result<Vec<Field>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let member = match &field.ident {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(Index::from(index)),
+            };
+            Ok(Field {
+                ty: field.ty.clone(),
+                options: FieldOptions::parse(&field.attrs)?,
+                member,
+                binding: format_ident!("__field{}", index),
+            })
+        })
+        .collect()
+}
+
+/// Returns true if given tokens refer to any of the type parameters.
+fn uses_params(tokens: TokenStream2, params: &[&syn::Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => params.contains(&&ident),
+        proc_macro2::TokenTree::Group(group) => uses_params(group.stream(), params),
+        _ => false,
+    })
+}
+
+/// Add a trait bound to the types of encoded fields which refer to type parameters.
+/// Skipped fields are not bounded, so their type parameters need not implement the trait.
+fn add_bounds(input: &DeriveInput, bound: TokenStream2) -> Result<syn::Generics> {
+    let mut generics = input.generics.clone();
+    let params = input
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect::<Vec<_>>();
+    let fields = match &input.data {
+        Data::Struct(data) => parse_fields(&data.fields)?,
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| parse_fields(&variant.fields))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+    let mut bounded = Vec::<String>::new();
+    for field in fields.iter().filter(|field| !field.options.skip) {
+        let ty = &field.ty;
+        let key = quote!(#ty).to_string();
+        if uses_params(quote!(#ty), &params) && !bounded.contains(&key) {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(#ty: #bound));
+            bounded.push(key);
+        }
+    }
+    Ok(generics)
+}
+
+/// Generate statements writing given fields, accessed through `access`.
+fn encode_fields(
+    krate: &syn::Path,
+    fields: &[Field],
+    access: impl Fn(&Field) -> TokenStream2,
+) -> TokenStream2 {
+    let writes = fields
+        .iter()
+        .filter(|field| !field.options.skip)
+        .map(|field| {
+            let value = access(field);
+            let value = match field.options.desc {
+                true => quote!(&::std::cmp::Reverse(#value)),
+                false => value,
+            };
+            quote! {
+                len += #krate::encode::AzamEncode::azam_encode_write(#value, writer)?;
+            }
+        });
+    quote!(#(#writes)*)
+}
+
+/// Generate constructor of given fields, decoded in field order.
+fn decode_fields(
+    krate: &syn::Path,
+    path: TokenStream2,
+    fields: &Fields,
+    parsed: &[Field],
+) -> TokenStream2 {
+    let values = parsed.iter().map(|field| {
+        let ty = &field.ty;
+        match (field.options.skip, field.options.desc) {
+            (true, _) => quote!(::std::default::Default::default()),
+            (false, true) => quote! {
+                <::std::cmp::Reverse<#ty> as #krate::decode::AzamDecode>::azam_decode_from(reader)?.0
+            },
+            (false, false) => quote! {
+                <#ty as #krate::decode::AzamDecode>::azam_decode_from(reader)?
+            },
+        }
+    });
+    match fields {
+        Fields::Named(_) => {
+            let members = parsed.iter().map(|field| &field.member);
+            quote!(#path { #(#members: #values),* })
+        }
+        Fields::Unnamed(_) => quote!(#path ( #(#values),* )),
+        Fields::Unit => quote!(#path),
+    }
+}
+
+/// Generate pattern binding all fields of an enum variant.
+fn variant_pattern(path: TokenStream2, fields: &Fields, parsed: &[Field]) -> TokenStream2 {
+    let bindings = parsed.iter().map(|field| {
+        let member = &field.member;
+        let binding = &field.binding;
+        match field.options.skip {
+            true => quote!(#member: _),
+            false => quote!(#member: #binding),
+        }
+    });
+    match fields {
+        Fields::Unit => quote!(#path),
+        _ => quote!(#path { #(#bindings),* }),
+    }
+}
+
+fn expand_encode(input: DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let krate = &ContainerOptions::parse(&input.attrs)?.krate;
+    let generics = add_bounds(&input, quote!(#krate::encode::AzamEncode))?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let fields = parse_fields(&data.fields)?;
+            encode_fields(krate, &fields, |field| {
+                let member = &field.member;
+                quote!(&self.#member)
+            })
+        }
+        // References to uninhabited types are not considered empty by match
+        Data::Enum(data) if data.variants.is_empty() => quote!(match *self {}),
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(index, variant)| {
+                    let ident = &variant.ident;
+                    let index = index as u32;
+                    let fields = parse_fields(&variant.fields)?;
+                    let pattern = variant_pattern(quote!(Self::#ident), &variant.fields, &fields);
+                    let writes = encode_fields(krate, &fields, |field| {
+                        let binding = &field.binding;
+                        quote!(#binding)
+                    });
+                    Ok(quote! {
+                        #pattern => {
+                            len += #krate::__private::encode_variant(#index, writer)?;
+                            #writes
+                        }
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "AzamEncode cannot be derived for unions",
+            ))
+        }
+    };
+    Ok(quote! {
+        impl #impl_generics #krate::encode::AzamEncode for #name #ty_generics #where_clause {
+            #[allow(unused_mut, unused_variables, unreachable_code)]
+            fn azam_encode_write<W: ::std::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> #krate::error::Result<usize> {
+                let mut len = 0usize;
+                #body
+                Ok(len)
+            }
+
+            fn azam_encode(&self) -> ::std::string::String {
+                let mut encoded = ::std::vec::Vec::<u8>::new();
+                self.azam_encode_write(&mut encoded).unwrap();
+                ::std::string::String::from_utf8(encoded).unwrap()
+            }
+        }
+    })
+}
+
+fn expand_decode(input: DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let krate = &ContainerOptions::parse(&input.attrs)?.krate;
+    let generics = add_bounds(&input, quote!(#krate::decode::AzamDecode))?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let fields = parse_fields(&data.fields)?;
+            let value = decode_fields(krate, quote!(Self), &data.fields, &fields);
+            quote!(Ok(#value))
+        }
+        Data::Enum(data) => {
+            let count = data.variants.len() as u32;
+            let arms = data
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(index, variant)| {
+                    let ident = &variant.ident;
+                    let index = index as u32;
+                    let fields = parse_fields(&variant.fields)?;
+                    let value =
+                        decode_fields(krate, quote!(Self::#ident), &variant.fields, &fields);
+                    Ok(quote!(#index => Ok(#value),))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            quote! {
+                match #krate::__private::decode_variant(reader, #count)? {
+                    #(#arms)*
+                    _ => unreachable!(),
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "AzamDecode cannot be derived for unions",
+            ))
+        }
+    };
+    Ok(quote! {
+        impl #impl_generics #krate::decode::AzamDecode for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn azam_decode_from<R: ::std::io::Read>(
+                reader: &mut #krate::decode::AzamReader<R>,
+            ) -> #krate::error::Result<Self> {
+                #body
+            }
+        }
+    })
+}
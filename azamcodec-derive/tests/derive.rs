@@ -0,0 +1,163 @@
+use azamcodec::{decode::AzamDecode, encode::AzamEncode, error::AzamErrorKind};
+use std::marker::PhantomData;
+
+#[derive(Debug, PartialEq, AzamEncode, AzamDecode)]
+struct Id {
+    record_id: u32,
+    type_id: u8,
+    variant_id: u16,
+}
+
+#[derive(Debug, PartialEq, AzamEncode, AzamDecode)]
+struct Pair(u8, String);
+
+#[derive(Debug, PartialEq, AzamEncode, AzamDecode)]
+struct Unit;
+
+#[derive(Debug, PartialEq, AzamEncode, AzamDecode)]
+struct Generic<T> {
+    value: T,
+    tag: u8,
+}
+
+/// Type parameters of skipped and marker fields need no bounds.
+#[derive(Debug, PartialEq, AzamEncode, AzamDecode)]
+struct Typed<T, U> {
+    id: u64,
+    entity: PhantomData<T>,
+    #[azam(skip)]
+    cache: Option<U>,
+}
+
+#[derive(Debug, PartialEq)]
+struct NotEncodable;
+
+mod renamed {
+    pub use azamcodec as codec;
+}
+
+#[derive(Debug, PartialEq, AzamEncode, AzamDecode)]
+#[azam(crate = "renamed::codec")]
+struct Renamed {
+    value: Option<u8>,
+}
+
+#[derive(Debug, PartialEq, AzamEncode, AzamDecode)]
+enum Shape {
+    Empty,
+    Point(i32, i32),
+    Circle { radius: u32 },
+}
+
+#[derive(Debug, PartialEq, AzamEncode, AzamDecode)]
+enum Never {}
+
+#[derive(Debug, PartialEq, AzamEncode, AzamDecode)]
+struct Event {
+    kind: u8,
+    #[azam(desc)]
+    timestamp: i64,
+    #[azam(skip)]
+    cached: Option<u32>,
+}
+
+#[test]
+fn test_derive_struct() {
+    let id = Id {
+        record_id: 0xdeadbeef,
+        type_id: 0x15,
+        variant_id: 0xc001,
+    };
+    assert_eq!("xytxvyyfh5wgg1", id.azam_encode());
+    assert_eq!(id, Id::azam_decode("xytxvyyfh5wgg1").unwrap());
+
+    let pair = Pair(1, "ab".to_string());
+    assert_eq!(pair, Pair::azam_decode(&pair.azam_encode()).unwrap());
+
+    assert_eq!("", Unit.azam_encode());
+    assert_eq!(Unit, Unit::azam_decode("").unwrap());
+
+    let generic = Generic {
+        value: (1u8, true),
+        tag: 2,
+    };
+    assert_eq!("11", (1u8, true).azam_encode());
+    assert_eq!("112", generic.azam_encode());
+    assert_eq!(generic, Generic::azam_decode("112").unwrap());
+}
+
+#[test]
+fn test_derive_enum() {
+    let shapes = [
+        Shape::Empty,
+        Shape::Point(-1, 2),
+        Shape::Point(3, 0),
+        Shape::Circle { radius: 7 },
+    ];
+    let mut encoded = shapes
+        .iter()
+        .map(|shape| shape.azam_encode())
+        .collect::<Vec<_>>();
+    assert_eq!("r0", encoded[0]);
+    for (shape, value) in shapes.iter().zip(&encoded) {
+        assert_eq!(shape, &Shape::azam_decode(value).unwrap());
+    }
+    // Variants sort in declaration order
+    let sorted = encoded.clone();
+    encoded.sort();
+    assert_eq!(sorted, encoded);
+
+    let err = Shape::azam_decode("rh3").unwrap_err();
+    assert_eq!(AzamErrorKind::InvalidSection, err.kind());
+    assert_eq!(0, err.offset());
+
+    // Overlong variant index must not wrap into a valid index
+    let err = Shape::azam_decode(&format!("thh{}1rh1rh2", "g".repeat(31))).unwrap_err();
+    assert_eq!(AzamErrorKind::InvalidSection, err.kind());
+    assert_eq!(0, err.offset());
+
+    // Enum without variants has no encoding
+    let err = Never::azam_decode("r0").unwrap_err();
+    assert_eq!(AzamErrorKind::InvalidSection, err.kind());
+    assert!(Option::<Never>::azam_decode("0").unwrap().is_none());
+    assert_eq!("0", Option::<Never>::None.azam_encode());
+}
+
+#[test]
+fn test_derive_attributes() {
+    let event = Event {
+        kind: 1,
+        timestamp: 100,
+        cached: Some(5),
+    };
+    let encoded = event.azam_encode();
+    let decoded = Event::azam_decode(&encoded).unwrap();
+    assert_eq!(1, decoded.kind);
+    assert_eq!(100, decoded.timestamp);
+    assert_eq!(None, decoded.cached);
+
+    // Descending field sorts newer events first
+    let newer = Event {
+        kind: 1,
+        timestamp: 200,
+        cached: None,
+    };
+    assert!(newer.azam_encode() < encoded);
+}
+
+#[test]
+fn test_derive_bounds_and_crate() {
+    let typed = Typed::<NotEncodable, NotEncodable> {
+        id: 0x15,
+        entity: PhantomData,
+        cache: Some(NotEncodable),
+    };
+    assert_eq!("h5", typed.azam_encode());
+    let decoded = Typed::<NotEncodable, NotEncodable>::azam_decode("h5").unwrap();
+    assert_eq!(0x15, decoded.id);
+    assert_eq!(None, decoded.cache);
+
+    let renamed = Renamed { value: Some(1) };
+    assert_eq!("11", renamed.azam_encode());
+    assert_eq!(renamed, Renamed::azam_decode("11").unwrap());
+}
@@ -1,6 +1,6 @@
 use azamcodec::azam_decode;
 use azamcodec::decode::AzamDecode;
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use uuid::Uuid;
 
 fn test_decoder(instance: &mut Criterion) {
@@ -9,7 +9,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = Uuid::parse_str("ffffffffffffffffffffffffffffffff").unwrap();
+            let _x = Uuid::parse_str(black_box("ffffffffffffffffffffffffffffffff")).unwrap();
         })
     });
 
@@ -18,7 +18,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = u8::azam_decode("zf");
+            let _x = u8::azam_decode(black_box("zf"));
         })
     });
 
@@ -27,7 +27,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = u16::azam_decode("zzzf");
+            let _x = u16::azam_decode(black_box("zzzf"));
         })
     });
 
@@ -36,7 +36,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = u32::azam_decode("zzzzzzzf");
+            let _x = u32::azam_decode(black_box("zzzzzzzf"));
         })
     });
 
@@ -45,7 +45,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = u64::azam_decode("zzzzzzzzzzzzzzzf");
+            let _x = u64::azam_decode(black_box("zzzzzzzzzzzzzzzf"));
         })
     });
 
@@ -54,7 +54,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = u128::azam_decode("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf");
+            let _x = u128::azam_decode(black_box("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf"));
         })
     });
 
@@ -63,7 +63,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zf", u8);
+            let _x = azam_decode!(black_box("zf"), u8);
         })
     });
 
@@ -72,7 +72,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzf", u16);
+            let _x = azam_decode!(black_box("zzzf"), u16);
         })
     });
 
@@ -81,7 +81,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzzf", u32);
+            let _x = azam_decode!(black_box("zzzzzzzf"), u32);
         })
     });
 
@@ -90,7 +90,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzzzzzzzzzzf", u64);
+            let _x = azam_decode!(black_box("zzzzzzzzzzzzzzzf"), u64);
         })
     });
 
@@ -99,7 +99,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf", u128);
+            let _x = azam_decode!(black_box("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf"), u128);
         })
     });
 
@@ -108,7 +108,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf", u128, u128);
+            let _x = azam_decode!(black_box("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf"), u128, u128);
         })
     });
 
@@ -117,7 +117,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf", u128, u128, u128);
+            let _x = azam_decode!(black_box("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf"), u128, u128, u128);
         })
     });
 
@@ -126,7 +126,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf", u128, u128, u128, u128);
+            let _x = azam_decode!(black_box("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf"), u128, u128, u128, u128);
         })
     });
 
@@ -135,7 +135,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf", u128, u128, u128, u128, u128);
+            let _x = azam_decode!(black_box("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf"), u128, u128, u128, u128, u128);
         })
     });
 }
@@ -1,6 +1,6 @@
 use azamcodec::azam_decode;
 use azamcodec::decode::AzamDecode;
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use uuid::Uuid;
 
 fn test_decoder(instance: &mut Criterion) {
@@ -9,7 +9,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = Uuid::parse_str("0fffffffffffffffffffffffffffffff").unwrap();
+            let _x = Uuid::parse_str(black_box("0fffffffffffffffffffffffffffffff")).unwrap();
         })
     });
 
@@ -18,7 +18,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = u8::azam_decode("f");
+            let _x = u8::azam_decode(black_box("f"));
         })
     });
 
@@ -27,7 +27,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = u16::azam_decode("zzf");
+            let _x = u16::azam_decode(black_box("zzf"));
         })
     });
 
@@ -36,7 +36,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = u32::azam_decode("zzzzzzf");
+            let _x = u32::azam_decode(black_box("zzzzzzf"));
         })
     });
 
@@ -45,7 +45,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = u64::azam_decode("zzzzzzzzzzzzzzf");
+            let _x = u64::azam_decode(black_box("zzzzzzzzzzzzzzf"));
         })
     });
 
@@ -54,7 +54,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = u128::azam_decode("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf");
+            let _x = u128::azam_decode(black_box("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf"));
         })
     });
 
@@ -63,7 +63,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("f", u8);
+            let _x = azam_decode!(black_box("f"), u8);
         })
     });
 
@@ -72,7 +72,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzf", u16);
+            let _x = azam_decode!(black_box("zzf"), u16);
         })
     });
 
@@ -81,7 +81,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzf", u32);
+            let _x = azam_decode!(black_box("zzzzzzf"), u32);
         })
     });
 
@@ -90,7 +90,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzzzzzzzzzf", u64);
+            let _x = azam_decode!(black_box("zzzzzzzzzzzzzzf"), u64);
         })
     });
 
@@ -99,7 +99,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf", u128);
+            let _x = azam_decode!(black_box("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf"), u128);
         })
     });
 
@@ -108,7 +108,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf", u128, u128);
+            let _x = azam_decode!(black_box("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf"), u128, u128);
         })
     });
 
@@ -117,7 +117,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf", u128, u128, u128);
+            let _x = azam_decode!(black_box("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf"), u128, u128, u128);
         })
     });
 
@@ -126,7 +126,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf", u128, u128, u128, u128);
+            let _x = azam_decode!(black_box("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf"), u128, u128, u128, u128);
         })
     });
 
@@ -135,7 +135,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_decode!("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf", u128, u128, u128, u128, u128);
+            let _x = azam_decode!(black_box("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf"), u128, u128, u128, u128, u128);
         })
     });
 }
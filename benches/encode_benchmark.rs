@@ -1,6 +1,6 @@
 use azamcodec::azam_encode;
 use azamcodec::encode::AzamEncode;
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use uuid::Uuid;
 
 fn test_decoder(instance: &mut Criterion) {
@@ -10,7 +10,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = uuid_value.to_string();
+            let _x = black_box(uuid_value).to_string();
         })
     });
 
@@ -19,7 +19,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = 0xffu8.azam_encode();
+            let _x = black_box(0xffu8).azam_encode();
         })
     });
 
@@ -28,7 +28,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = 0xffffu16.azam_encode();
+            let _x = black_box(0xffffu16).azam_encode();
         })
     });
 
@@ -37,7 +37,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = 0xffffffffu32.azam_encode();
+            let _x = black_box(0xffffffffu32).azam_encode();
         })
     });
 
@@ -46,7 +46,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = 0xffffffffffffffffu64.azam_encode();
+            let _x = black_box(0xffffffffffffffffu64).azam_encode();
         })
     });
 
@@ -55,7 +55,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = 0xffffffffffffffffffffffffffffffffu128.azam_encode();
+            let _x = black_box(0xffffffffffffffffffffffffffffffffu128).azam_encode();
         })
     });
 
@@ -64,7 +64,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_encode!(0xffu8);
+            let _x = azam_encode!(black_box(0xffu8));
         })
     });
 
@@ -73,7 +73,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_encode!(0xffffu16);
+            let _x = azam_encode!(black_box(0xffffu16));
         })
     });
 
@@ -82,7 +82,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_encode!(0xffffffffu32);
+            let _x = azam_encode!(black_box(0xffffffffu32));
         })
     });
 
@@ -91,7 +91,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_encode!(0xffffffffffffffffu64);
+            let _x = azam_encode!(black_box(0xffffffffffffffffu64));
         })
     });
 
@@ -100,7 +100,7 @@ fn test_decoder(instance: &mut Criterion) {
 
         // Benchmark iteration in closure
         bencher.iter(|| {
-            let _x = azam_encode!(0xffffffffffffffffffffffffffffffffu128);
+            let _x = azam_encode!(black_box(0xffffffffffffffffffffffffffffffffu128));
         })
     });
 
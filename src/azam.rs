@@ -0,0 +1,303 @@
+use crate::decode::AzamDecode;
+use crate::encode::AzamEncode;
+use crate::error::AzamError;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// Wrapper of a value implementing [`AzamEncode`], which displays, parses, compares and hashes the value as Azam codec encoded string.
+///
+/// Symbols are written straight into the formatter, so formatting does not allocate an intermediate string.
+/// Wrap a reference to avoid moving the value, which works for any [`AzamEncode`] type including tuples of multiple sections.
+///
+/// Equality, ordering and hashing are those of the encoded strings, so wrapped values sort in a map the same as their encoded strings.
+/// This differs from the order of inner value for types that are not order-preserving, e.g. `0xffu16` (`"zf"`) sorts after `0x100u16` (`"hg0"`).
+/// Comparison encodes each value once into a stack buffer, which only allocates for values longer than 128 symbols, and hashing streams the encoded symbols without allocation.
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::Azam;
+///
+/// let id = (0xdeadbeefu32, 0x15u8, 0xc001u16);
+/// let line = format!("id={}", Azam(&id)); // "id=xytxvyyfh5wgg1"
+///
+/// let id: Azam<u32> = "xytxvyyf".parse().unwrap(); // Azam(0xdeadbeefu32)
+/// assert!("xytxvyyfh5".parse::<Azam<u32>>().is_err());
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct Azam<T>(pub T);
+
+impl<T> Azam<T> {
+    /// Returns the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Azam<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: AzamEncode> fmt::Display for Azam<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.azam_encode_fmt(f)
+    }
+}
+
+/// Debug output has both the encoded string and inner value, e.g. `Azam("xytxvyyf", 3735928559)`.
+impl<T: AzamEncode + fmt::Debug> fmt::Debug for Azam<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Azam")
+            .field(&format_args!("\"{}\"", self))
+            .field(&self.0)
+            .finish()
+    }
+}
+
+/// Parse a string of a single encoded value, and reject any trailing data with an [`AzamErrorKind::TrailingData`](crate::error::AzamErrorKind::TrailingData) error.
+impl<T: AzamDecode> FromStr for Azam<T> {
+    type Err = AzamError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        T::azam_decode_exact(value).map(Self)
+    }
+}
+
+impl<T: AzamDecode> TryFrom<&str> for Azam<T> {
+    type Error = AzamError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl<T: AzamEncode> PartialEq for Azam<T> {
+    fn eq(&self, other: &Self) -> bool {
+        encoded_cmp(&self.0, &other.0) == Ordering::Equal
+    }
+}
+
+impl<T: AzamEncode> Eq for Azam<T> {}
+
+impl<T: AzamEncode> PartialOrd for Azam<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AzamEncode> Ord for Azam<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        encoded_cmp(&self.0, &other.0)
+    }
+}
+
+/// Hash the encoded symbols, so values that are equal as encoded strings hash the same.
+impl<T: AzamEncode> Hash for Azam<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut writer = HashWriter {
+            state,
+            buf: [0; CHUNK_LEN],
+            len: 0,
+        };
+        // Writers into memory do not fail, same as `azam_encode()`
+        self.0.azam_encode_write(&mut writer).unwrap();
+        writer.state.write(&writer.buf[..writer.len]);
+        writer.state.write_u8(0xff);
+    }
+}
+
+/// Number of encoded bytes hashed at once.
+const CHUNK_LEN: usize = 64;
+
+/// Number of encoded bytes compared without allocation.
+const INLINE_LEN: usize = 128;
+
+/// Compare encoded strings of two values, which are encoded once each.
+fn encoded_cmp<T: AzamEncode>(left: &T, right: &T) -> Ordering {
+    Encoded::encode(left)
+        .as_bytes()
+        .cmp(Encoded::encode(right).as_bytes())
+}
+
+/// Writer that keeps encoded bytes on stack, and moves them to heap once they exceed [`INLINE_LEN`].
+struct Encoded {
+    inline: [u8; INLINE_LEN],
+    len: usize,
+    heap: Vec<u8>,
+}
+
+impl Encoded {
+    fn encode<T: AzamEncode>(value: &T) -> Self {
+        let mut encoded = Self {
+            inline: [0; INLINE_LEN],
+            len: 0,
+            heap: Vec::new(),
+        };
+        // Writers into memory do not fail, same as `azam_encode()`
+        value.azam_encode_write(&mut encoded).unwrap();
+        encoded
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        match self.heap.is_empty() {
+            true => &self.inline[..self.len],
+            false => &self.heap,
+        }
+    }
+}
+
+impl Write for Encoded {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.heap.is_empty() && self.len + buf.len() <= INLINE_LEN {
+            self.inline[self.len..self.len + buf.len()].copy_from_slice(buf);
+            self.len += buf.len();
+        } else {
+            if self.heap.is_empty() {
+                self.heap.reserve(2 * (self.len + buf.len()));
+                self.heap.extend_from_slice(&self.inline[..self.len]);
+            }
+            self.heap.extend_from_slice(buf);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Writer that feeds encoded bytes to a [`Hasher`] in fixed size chunks.
+/// Chunks do not depend on how encoders split their writes, so equal encoded strings hash the same.
+struct HashWriter<'a, H: Hasher> {
+    state: &'a mut H,
+    buf: [u8; CHUNK_LEN],
+    len: usize,
+}
+
+impl<H: Hasher> Write for HashWriter<'_, H> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut rest = buf;
+        while !rest.is_empty() {
+            let n = rest.len().min(CHUNK_LEN - self.len);
+            self.buf[self.len..self.len + n].copy_from_slice(&rest[..n]);
+            self.len += n;
+            rest = &rest[n..];
+            if self.len == CHUNK_LEN {
+                self.state.write(&self.buf);
+                self.len = 0;
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::azam::*;
+    use crate::error::AzamErrorKind;
+    use std::collections::{BTreeSet, HashSet};
+
+    #[test]
+    fn test_azam_display() {
+        assert_eq!("xytxvyyf", Azam(0xdeadbeefu32).to_string());
+        assert_eq!("xytxvyyf", format!("{}", Azam(&0xdeadbeefu32)));
+        assert_eq!(
+            "id=xytxvyyfh5wgg1 parent=qzf",
+            format!(
+                "id={} parent={}",
+                Azam(&(0xdeadbeefu32, 0x15u8, 0xc001u16)),
+                Azam(-1i64)
+            )
+        );
+        assert_eq!("1hph0", Azam(Some("a".to_string())).to_string());
+        assert_eq!("", Azam(()).to_string());
+        assert_eq!(
+            "Azam(\"xytxvyyf\", 3735928559)",
+            format!("{:?}", Azam(0xdeadbeefu32))
+        );
+    }
+
+    #[test]
+    fn test_azam_parse() {
+        assert_eq!(0xdeadbeefu32, "xytxvyyf".parse::<Azam<u32>>().unwrap().0);
+        assert_eq!(
+            (0xdeadbeefu32, 0x15u8, 0xc001u16),
+            Azam::<(u32, u8, u16)>::try_from("xytxvyyfh5wgg1")
+                .unwrap()
+                .into_inner()
+        );
+        let err = "xytxvyyfh5".parse::<Azam<u32>>().unwrap_err();
+        assert_eq!(AzamErrorKind::TrailingData, err.kind());
+        assert_eq!(8, err.offset());
+        assert_eq!(Some("xytxvyyfh5"), err.input());
+        let err = Azam::<u32>::try_from("xy_txvyyf").unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSymbol, err.kind());
+        let err = "".parse::<Azam<u32>>().unwrap_err();
+        assert_eq!(AzamErrorKind::Truncated, err.kind());
+    }
+
+    #[test]
+    fn test_azam_order() {
+        let values = [0x1000u16, 0xff, 0x100, 0x1, 0x10];
+        let sorted = values
+            .iter()
+            .map(|&value| Azam(value))
+            .collect::<BTreeSet<_>>();
+        let mut encoded = values
+            .iter()
+            .map(|value| Azam(value).to_string())
+            .collect::<Vec<_>>();
+        encoded.sort();
+        assert_eq!(
+            encoded,
+            sorted.iter().map(Azam::to_string).collect::<Vec<_>>()
+        );
+        assert!(Azam(0xffu16) > Azam(0x1000u16));
+        assert!(Azam(-1i32) < Azam(0i32));
+        assert_eq!(Azam(1u8), Azam::from(1u8));
+        let set = HashSet::from([Azam(1u64), Azam(1u64), Azam(2u64)]);
+        assert_eq!(2, set.len());
+    }
+
+    #[test]
+    fn test_azam_order_long() {
+        // Encoded strings are longer than the stack buffer, and differ before, at or after its end
+        let base = "a".repeat(100);
+        let values = [
+            base.clone(),
+            format!("{}b", base),
+            format!("{}a", base),
+            "a".repeat(62),
+            "a".repeat(63),
+            "a".repeat(64),
+            format!("{}b", "a".repeat(70)),
+            String::new(),
+        ];
+        for left in &values {
+            for right in &values {
+                assert_eq!(
+                    Azam(left).to_string().cmp(&Azam(right).to_string()),
+                    Azam(left).cmp(&Azam(right)),
+                    "{:?} {:?}",
+                    left,
+                    right
+                );
+                assert_eq!(left == right, Azam(left) == Azam(right));
+            }
+        }
+        let set = values
+            .iter()
+            .chain(values.iter())
+            .map(Azam)
+            .collect::<HashSet<_>>();
+        assert_eq!(values.len(), set.len());
+    }
+}
@@ -0,0 +1,316 @@
+use crate::error::{AzamError, AzamErrorKind, Result};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// Fixed capacity string of Azam codec encoded bytes, which is stored inline without allocation.
+/// Dereferences to [`str`], and compares, orders and hashes the same as the encoded string.
+///
+/// Use [`max_encoded_len`](crate::encode::max_encoded_len) to size the buffer for a type with bounded encoded length.
+///
+/// # Examples
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use azamcodec::{buf::AzamBuf, encode::{max_encoded_len, AzamEncode}};
+///
+/// let encoded: AzamBuf<{ max_encoded_len::<u32>() }> = 0xdeadbeefu32.azam_encode_to_buf().unwrap();
+/// assert_eq!("xytxvyyf", &*encoded);
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct AzamBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> AzamBuf<N> {
+    /// Create a new empty buffer.
+    pub const fn new() -> Self {
+        Self {
+            buf: [0u8; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the encoded string.
+    pub fn as_str(&self) -> &str {
+        // Only whole UTF-8 strings are appended, see push_str
+        core::str::from_utf8(self.as_bytes()).unwrap()
+    }
+
+    /// Returns the encoded bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Returns the number of encoded bytes.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the buffer is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the capacity of the buffer, which is `N`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Remove all bytes of the buffer.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Shorten the buffer to given length, which has no effect if it is not shorter than the current length.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - New length of the buffer, which must be at a char boundary
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            assert!(self.as_str().is_char_boundary(len));
+            self.len = len;
+        }
+    }
+
+    /// Append given string to the buffer, or return [`AzamErrorKind::BufferTooSmall`] error if it does not fit, in which case nothing is appended.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - String to append
+    pub fn push_str(&mut self, value: &str) -> Result<()> {
+        let end = self.len + value.len();
+        if end > N {
+            return Err(AzamError::new(AzamErrorKind::BufferTooSmall));
+        }
+        self.buf[self.len..end].copy_from_slice(value.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl<const N: usize> Default for AzamBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for AzamBuf<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for AzamBuf<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for AzamBuf<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> Borrow<str> for AzamBuf<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Display for AzamBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Debug for AzamBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<AzamBuf<M>> for AzamBuf<N> {
+    fn eq(&self, other: &AzamBuf<M>) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> Eq for AzamBuf<N> {}
+
+impl<const N: usize> PartialEq<str> for AzamBuf<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for AzamBuf<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> PartialEq<AzamBuf<N>> for str {
+    fn eq(&self, other: &AzamBuf<N>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq<AzamBuf<N>> for &str {
+    fn eq(&self, other: &AzamBuf<N>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl<const N: usize, const M: usize> PartialOrd<AzamBuf<M>> for AzamBuf<N> {
+    fn partial_cmp(&self, other: &AzamBuf<M>) -> Option<Ordering> {
+        Some(self.as_bytes().cmp(other.as_bytes()))
+    }
+}
+
+impl<const N: usize> Ord for AzamBuf<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+/// Hash the same as [`str`], so buffers can be looked up by string in hash maps.
+impl<const N: usize> Hash for AzamBuf<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const N: usize> fmt::Write for AzamBuf<N> {
+    fn write_str(&mut self, value: &str) -> fmt::Result {
+        self.push_str(value).map_err(|_| fmt::Error)
+    }
+}
+
+/// Only whole UTF-8 strings can be written, so the buffer is always a valid string.
+/// Writes that do not fit fail with [`AzamErrorKind::BufferTooSmall`] error, and nothing is written.
+#[cfg(feature = "std")]
+impl<const N: usize> std::io::Write for AzamBuf<N> {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        let value = core::str::from_utf8(data)
+            .map_err(|_| std::io::Error::from(AzamError::new(AzamErrorKind::InvalidUtf8)))?;
+        self.push_str(value)?;
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::buf::*;
+    #[cfg(feature = "std")]
+    use crate::encode::{max_encoded_len, AzamEncode};
+    #[cfg(feature = "std")]
+    use std::cmp::Reverse;
+    #[cfg(feature = "std")]
+    use std::collections::HashSet;
+    #[cfg(feature = "std")]
+    use std::io::Write;
+    use std::{format, string::ToString};
+
+    #[test]
+    fn test_azam_buf() {
+        let mut buf = AzamBuf::<8>::new();
+        assert!(buf.is_empty());
+        assert_eq!(8, buf.capacity());
+        buf.push_str("xytx").unwrap();
+        buf.push_str("vyyf").unwrap();
+        assert_eq!("xytxvyyf", buf);
+        assert_eq!(8, buf.len());
+        assert_eq!(b"xytxvyyf", AsRef::<[u8]>::as_ref(&buf));
+        assert_eq!("\"xytxvyyf\"", format!("{:?}", buf));
+        assert_eq!("xytxvyyf", buf.to_string());
+        assert_eq!(
+            AzamErrorKind::BufferTooSmall,
+            buf.push_str("h").unwrap_err().kind()
+        );
+        assert_eq!("xytxvyyf", buf);
+        buf.truncate(4);
+        assert_eq!("xytx", buf);
+        buf.clear();
+        assert_eq!("", buf);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_buf_write() {
+        let mut buf = AzamBuf::<4>::new();
+        buf.write_all(b"h5").unwrap();
+        let err = AzamError::from(buf.write_all(b"wgg1").unwrap_err());
+        assert_eq!(AzamErrorKind::BufferTooSmall, err.kind());
+        assert_eq!("h5", buf);
+        let err = AzamError::from(buf.write_all(&[0xff]).unwrap_err());
+        assert_eq!(AzamErrorKind::InvalidUtf8, err.kind());
+        std::fmt::Write::write_str(&mut buf, "1").unwrap();
+        assert!(std::fmt::Write::write_str(&mut buf, "23").is_err());
+        assert_eq!("h51", buf);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_buf_order() {
+        let a: AzamBuf<4> = 1i8.azam_encode_to_buf().unwrap();
+        let b: AzamBuf<4> = 100i8.azam_encode_to_buf().unwrap();
+        let c: AzamBuf<34> = 100i128.azam_encode_to_buf().unwrap();
+        assert!(a < b);
+        assert_eq!(b, c);
+        let set = HashSet::from([a, b]);
+        assert!(set.contains("rh1"));
+        assert!(set.contains("rjp4"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_to_buf() {
+        let encoded: AzamBuf<{ max_encoded_len::<u128>() }> =
+            u128::MAX.azam_encode_to_buf().unwrap();
+        assert_eq!(u128::MAX.azam_encode(), encoded.as_str());
+        let encoded: AzamBuf<{ max_encoded_len::<(u32, u8, u16)>() }> =
+            (0xdeadbeefu32, 0x15u8, 0xc001u16)
+                .azam_encode_to_buf()
+                .unwrap();
+        assert_eq!("xytxvyyfh5wgg1", encoded);
+        let mut buf = AzamBuf::<16>::new();
+        assert_eq!(2, 0x15u8.azam_encode_into_buf(&mut buf).unwrap());
+        assert_eq!(4, 0xc001u16.azam_encode_into_buf(&mut buf).unwrap());
+        assert_eq!("h5wgg1", buf);
+        let err = "abc".azam_encode_to_buf::<4>().unwrap_err();
+        assert_eq!(AzamErrorKind::BufferTooSmall, err.kind());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_max_encoded_len() {
+        assert_eq!(32, max_encoded_len::<u128>());
+        assert_eq!(2, max_encoded_len::<u8>());
+        assert_eq!(4, max_encoded_len::<i8>());
+        assert_eq!(34, max_encoded_len::<i128>());
+        assert_eq!(18, max_encoded_len::<f64>());
+        assert_eq!(8, max_encoded_len::<char>());
+        assert_eq!(5, max_encoded_len::<Option<i8>>());
+        assert_eq!(14, max_encoded_len::<(u32, u8, u16)>());
+        for value in [i8::MIN, -1, 0, 1, i8::MAX] {
+            assert!(value.azam_encode().len() <= max_encoded_len::<i8>());
+        }
+        for value in [f64::MIN, -0.0, f64::NAN, f64::INFINITY] {
+            assert!(value.azam_encode().len() <= max_encoded_len::<f64>());
+        }
+        assert_eq!(8, char::MAX.azam_encode().len());
+        assert!(Reverse(u8::MAX).azam_encode().len() <= max_encoded_len::<Reverse<u8>>());
+    }
+}
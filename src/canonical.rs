@@ -0,0 +1,341 @@
+//! Decoders accept aliases of symbols, `o`/`O` for `0` and `i`/`l`/`I`/`L` for `1`, and uppercase letters, so different strings may decode to the same value.
+//! Canonical form uses only `0-9a-f` for low nybbles and `ghjkmnpqrstvwxyz` for high nybbles, which is what encoders produce.
+//!
+//! [`canonicalize`](crate::canonical::canonicalize) and [`is_canonical`](crate::canonical::is_canonical) normalize and check strings, e.g. user-entered identifiers, and [`AzamReader::strict`](crate::decode::AzamReader::strict) rejects non-canonical input while decoding.
+//! [`canonical_cmp`](crate::canonical::canonical_cmp), [`canonical_eq`](crate::canonical::canonical_eq) and [`canonical_hash`](crate::canonical::canonical_hash) treat alias-equivalent strings as equal without allocation, and [`Canonical`](crate::canonical::Canonical) wraps strings to use them as map keys.
+
+use crate::decode::nybble_value;
+use crate::encode::{HIGHER_ALPHABETS, LOWER_ALPHABETS};
+use crate::error::{AzamError, AzamErrorKind, Result};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// Returns the canonical symbol for given byte symbol, or None if it is not a valid symbol.
+///
+/// # Arguments
+///
+/// * `value` - Byte symbol, which may be an alias or uppercase
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::canonical::canonical_symbol;
+///
+/// assert_eq!(Some(b'x'), canonical_symbol(b'X'));
+/// assert_eq!(Some(b'1'), canonical_symbol(b'l'));
+/// assert_eq!(None, canonical_symbol(b'_'));
+/// ```
+#[inline]
+pub const fn canonical_symbol(value: u8) -> Option<u8> {
+    match nybble_value(value) {
+        None => None,
+        Some(nybble) if nybble >> 4 == 0x00u8 => Some(LOWER_ALPHABETS[nybble as usize]),
+        Some(nybble) => Some(HIGHER_ALPHABETS[(nybble & 0x0fu8) as usize]),
+    }
+}
+
+/// Validate sections of given Azam codec encoded bytes, and call `visit` with offset, byte and nybble value of each symbol.
+fn visit_sections<F>(value: &[u8], mut visit: F) -> Result<()>
+where
+    F: FnMut(usize, u8, u8) -> Result<()>,
+{
+    let (mut section, mut symbols) = (0usize, 0usize);
+    for (offset, &byte) in value.iter().enumerate() {
+        let nybble = nybble_value(byte).ok_or_else(|| {
+            AzamError::new(AzamErrorKind::InvalidSymbol)
+                .at(offset, section)
+                .with_byte(byte)
+        })?;
+        if symbols == 0 && nybble == 0x10u8 {
+            return Err(AzamError::new(AzamErrorKind::LeadingZero)
+                .at(offset, section)
+                .with_byte(byte));
+        }
+        visit(offset, byte, nybble).map_err(|err| err.at(offset, section).with_byte(byte))?;
+        if nybble >> 4 == 0x00u8 {
+            section += 1;
+            symbols = 0;
+        } else {
+            symbols += 1;
+        }
+    }
+    match symbols {
+        0 => Ok(()),
+        _ => Err(AzamError::new(AzamErrorKind::Truncated).at(value.len(), section)),
+    }
+}
+
+/// Returns true if given string is valid Azam codec of complete sections, which has only canonical symbols.
+///
+/// # Arguments
+///
+/// * `value` - Azam codec encoded string
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::canonical::is_canonical;
+///
+/// assert!(is_canonical("xytxvyyfh5wgg1"));
+/// assert!(!is_canonical("XYTXVYYFh5wgg1"));
+/// assert!(!is_canonical("xytxvyyfh5wggl"));
+/// assert!(!is_canonical("xytxvyyfh5wgg"));
+/// ```
+pub fn is_canonical(value: &str) -> bool {
+    visit_sections(value.as_bytes(), |_, byte, _| {
+        match canonical_symbol(byte) == Some(byte) {
+            true => Ok(()),
+            false => Err(AzamErrorKind::NonCanonical.into()),
+        }
+    })
+    .is_ok()
+}
+
+/// Validate given Azam codec encoded string, and return its canonical form with aliases and uppercase letters replaced by canonical symbols.
+/// Returns an error if the string has an invalid symbol, a section with a leading zero, or an incomplete last section.
+///
+/// # Arguments
+///
+/// * `value` - Azam codec encoded string
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::canonical::canonicalize;
+///
+/// let x = canonicalize("XYTXVYYFH5WGGl").unwrap(); // "xytxvyyfh5wgg1"
+/// ```
+#[cfg(feature = "alloc")]
+pub fn canonicalize(value: &str) -> Result<String> {
+    let mut canonical = String::with_capacity(value.len());
+    visit_sections(value.as_bytes(), |_, byte, _| {
+        canonical.push(canonical_symbol(byte).unwrap_or_default() as char);
+        Ok(())
+    })
+    .map_err(|err| err.with_input(value))?;
+    Ok(canonical)
+}
+
+/// Map each byte to its canonical symbol, and keep invalid bytes as is.
+fn canonical_bytes(value: &str) -> impl Iterator<Item = u8> + '_ {
+    value
+        .bytes()
+        .map(|byte| canonical_symbol(byte).unwrap_or(byte))
+}
+
+/// Compare two Azam codec encoded strings by their canonical forms, without allocation.
+/// Alias-equivalent strings compare equal, and other strings compare the same as their canonical forms.
+///
+/// # Arguments
+///
+/// * `a` - Azam codec encoded string
+/// * `b` - Azam codec encoded string
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::canonical::canonical_cmp;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(Ordering::Equal, canonical_cmp("XYTXVYYF", "xytxvyyf"));
+/// assert_eq!(Ordering::Less, canonical_cmp("Ah0", "b"));
+/// ```
+pub fn canonical_cmp(a: &str, b: &str) -> Ordering {
+    canonical_bytes(a).cmp(canonical_bytes(b))
+}
+
+/// Returns true if two Azam codec encoded strings have the same canonical form, without allocation.
+///
+/// # Arguments
+///
+/// * `a` - Azam codec encoded string
+/// * `b` - Azam codec encoded string
+pub fn canonical_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len() && canonical_bytes(a).eq(canonical_bytes(b))
+}
+
+/// Feed the canonical form of given Azam codec encoded string to a hasher, without allocation.
+/// Alias-equivalent strings feed the same bytes, so this is consistent with [`canonical_eq`].
+///
+/// # Arguments
+///
+/// * `value` - Azam codec encoded string
+/// * `state` - Hasher to feed
+pub fn canonical_hash<H: Hasher>(value: &str, state: &mut H) {
+    // Strings of the same canonical form are always split into the same chunks
+    let mut chunk = [0u8; 64];
+    let mut len = 0usize;
+    for byte in canonical_bytes(value) {
+        if len == chunk.len() {
+            state.write(&chunk);
+            len = 0;
+        }
+        chunk[len] = byte;
+        len += 1;
+    }
+    state.write(&chunk[..len]);
+    state.write_u8(0xff);
+}
+
+/// Wrapper of an Azam codec encoded string, which compares, orders and hashes by canonical form.
+/// Use this to key maps by user-entered identifiers, so alias-equivalent strings find the same entry.
+/// Displays as canonical form.
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::canonical::Canonical;
+/// use std::collections::HashMap;
+///
+/// let mut orders = HashMap::new();
+/// orders.insert(Canonical("xytxvyyf"), 1);
+/// assert_eq!(Some(&1), orders.get(&Canonical("XYTXVYYF")));
+/// assert_eq!("xytxvyyf", Canonical("XYTXVYYF").to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Canonical<S>(pub S);
+
+impl<S: AsRef<str>> PartialEq for Canonical<S> {
+    fn eq(&self, other: &Self) -> bool {
+        canonical_eq(self.0.as_ref(), other.0.as_ref())
+    }
+}
+
+impl<S: AsRef<str>> Eq for Canonical<S> {}
+
+impl<S: AsRef<str>> PartialOrd for Canonical<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: AsRef<str>> Ord for Canonical<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        canonical_cmp(self.0.as_ref(), other.0.as_ref())
+    }
+}
+
+impl<S: AsRef<str>> Hash for Canonical<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        canonical_hash(self.0.as_ref(), state)
+    }
+}
+
+impl<S: AsRef<str>> fmt::Display for Canonical<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for symbol in self.0.as_ref().chars() {
+            // Invalid symbols, including non-ASCII characters, are displayed as is
+            let symbol = u8::try_from(symbol)
+                .ok()
+                .and_then(canonical_symbol)
+                .map_or(symbol, char::from);
+            fmt::Write::write_char(f, symbol)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canonical::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeSet, HashSet};
+    use std::{string::ToString, vec, vec::Vec};
+
+    fn hash_of(value: &str) -> u64 {
+        let mut state = DefaultHasher::new();
+        canonical_hash(value, &mut state);
+        state.finish()
+    }
+
+    #[test]
+    fn test_canonical_symbol() {
+        for (symbol, canonical) in b"0123456789abcdefghjkmnpqrstvwxyz"
+            .iter()
+            .zip(b"0123456789abcdefghjkmnpqrstvwxyz")
+        {
+            assert_eq!(Some(*canonical), canonical_symbol(*symbol));
+            assert_eq!(
+                Some(*canonical),
+                canonical_symbol(symbol.to_ascii_uppercase())
+            );
+        }
+        for symbol in b"oO" {
+            assert_eq!(Some(b'0'), canonical_symbol(*symbol));
+        }
+        for symbol in b"ilIL" {
+            assert_eq!(Some(b'1'), canonical_symbol(*symbol));
+        }
+        for symbol in b"uU_- " {
+            assert_eq!(None, canonical_symbol(*symbol));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_canonicalize() {
+        assert_eq!("xytxvyyfh5wgg1", canonicalize("XYTXVYYFH5WGGl").unwrap());
+        assert_eq!("h0111", canonicalize("HoIlL").unwrap());
+        assert_eq!("", canonicalize("").unwrap());
+        let err = canonicalize("xytxvyyfh5w_g1").unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSymbol, err.kind());
+        assert_eq!(11, err.offset());
+        assert_eq!(2, err.section());
+        assert_eq!(Some("xytxvyyfh5w_g1"), err.input());
+        let err = canonicalize("h5Gg1").unwrap_err();
+        assert_eq!(AzamErrorKind::LeadingZero, err.kind());
+        assert_eq!(2, err.offset());
+        let err = canonicalize("h5wgg").unwrap_err();
+        assert_eq!(AzamErrorKind::Truncated, err.kind());
+        assert_eq!(5, err.offset());
+        assert_eq!(1, err.section());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_is_canonical() {
+        assert!(is_canonical("xytxvyyfh5wgg1"));
+        assert!(is_canonical(""));
+        assert!(!is_canonical("xytxvyyfh5wggL"));
+        assert!(!is_canonical("xytxvyyfH5wgg1"));
+        assert!(!is_canonical("ho"));
+        assert!(!is_canonical("h5wgg"));
+        assert!(!is_canonical("g1"));
+        assert!(!is_canonical("h_"));
+        for value in ["xytxvyyfh5wgg1", "HoIlL", "Ah0"] {
+            assert!(is_canonical(&canonicalize(value).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_canonical_cmp() {
+        assert_eq!(Ordering::Equal, canonical_cmp("XYTXVYYF", "xytxvyyf"));
+        assert_eq!(Ordering::Equal, canonical_cmp("hO", "h0"));
+        assert_eq!(Ordering::Less, canonical_cmp("Ah0", "b"));
+        assert_eq!(Ordering::Greater, canonical_cmp("G", "1"));
+        assert!(canonical_eq("hIlL", "h111"));
+        assert!(!canonical_eq("h11", "h111"));
+        assert_eq!(hash_of("XYTXVYYFH5WGGl"), hash_of("xytxvyyfh5wgg1"));
+        let long = "z".repeat(100) + "f";
+        assert_eq!(hash_of(&long.to_uppercase()), hash_of(&long));
+        assert_ne!(hash_of("h1"), hash_of("h2"));
+    }
+
+    #[test]
+    fn test_canonical_key() {
+        let keys = HashSet::from([
+            Canonical("xytxvyyf"),
+            Canonical("XYTXVYYF"),
+            Canonical("h5"),
+        ]);
+        assert_eq!(2, keys.len());
+        assert!(keys.contains(&Canonical("xYtXvYyF")));
+        let keys = ["b", "Ah0", "a"]
+            .into_iter()
+            .map(|key| Canonical(key.to_string()))
+            .collect::<BTreeSet<_>>();
+        assert_eq!(
+            vec!["a", "ah0", "b"],
+            keys.iter().map(Canonical::to_string).collect::<Vec<_>>()
+        );
+        assert_eq!("x_\u{e9}1", Canonical("X_\u{e9}l").to_string());
+    }
+}
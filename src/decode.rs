@@ -1,5 +1,23 @@
-use std::io::{Error, ErrorKind, Read, Result, Write};
+#[cfg(feature = "std")]
+use crate::canonical::canonical_symbol;
+use crate::error::{AzamError, AzamErrorKind, Result};
+#[cfg(feature = "std")]
+use crate::options::DecodeOptions;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::cmp::Reverse;
+#[cfg(feature = "std")]
+use std::io::{Read, Write};
+#[cfg(feature = "std")]
+use std::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+};
 
+#[cfg(feature = "std")]
 const INITIAL_CAPACITY: usize = if cfg!(target_pointer_width = "64") {
     8
 } else if cfg!(target_pointer_width = "32") {
@@ -8,10 +26,36 @@ const INITIAL_CAPACITY: usize = if cfg!(target_pointer_width = "64") {
     2
 };
 
+/// Derive macro of [`AzamDecode`] trait, available with `derive` feature.
+#[cfg(feature = "derive")]
+pub use azamcodec_derive::AzamDecode;
+
 /// Returns the nybble value [0..15] for given byte symbol.
 /// If nybble is a high nybble, 16 will be added to the value.
 /// If give byte symbol is not a valid symbol, None is returned.
-const fn nybble_value(value: u8) -> Option<u8> {
+#[inline]
+pub(crate) const fn nybble_value(value: u8) -> Option<u8> {
+    match NYBBLE_VALUES[value as usize] {
+        0xffu8 => None,
+        nybble => Some(nybble),
+    }
+}
+
+/// Lookup table of nybble values for all bytes, where invalid symbols are marked as 0xff.
+static NYBBLE_VALUES: [u8; 256] = {
+    let mut table = [0xffu8; 256];
+    let mut index = 0usize;
+    while index < table.len() {
+        if let Some(nybble) = nybble_symbol_value(index as u8) {
+            table[index] = nybble;
+        }
+        index += 1;
+    }
+    table
+};
+
+/// Returns the nybble value for given byte symbol, used to build [`NYBBLE_VALUES`].
+const fn nybble_symbol_value(value: u8) -> Option<u8> {
     match value {
         // Lower nybble
         b'0' | b'o' | b'O' => Some(0x00u8),
@@ -68,6 +112,7 @@ const fn nybble_value(value: u8) -> Option<u8> {
 /// azam_decode_read_until(&mut src, &mut dst, 8).unwrap();
 /// let decoded = u32::from_be_bytes(dst.as_slice()[..4].try_into().unwrap()); // 0xdeadbeefu32
 /// ```
+#[cfg(feature = "std")]
 pub fn azam_decode_read_until<R: Read + ?Sized, W: Write>(
     reader: &mut R,
     writer: &mut W,
@@ -77,11 +122,233 @@ pub fn azam_decode_read_until<R: Read + ?Sized, W: Write>(
     azam_decode_read(limited_reader, writer)
 }
 
+/// Returns the number of symbols in the first section of given Azam codec encoded bytes.
+/// Returns an error if the section contains an invalid symbol or does not terminate.
+pub(crate) fn section_len(value: &[u8]) -> Result<usize> {
+    for (index, byte) in value.iter().enumerate() {
+        let value = nybble_value(*byte).ok_or_else(|| {
+            AzamError::new(AzamErrorKind::InvalidSymbol)
+                .at(index, 0)
+                .with_byte(*byte)
+        })?;
+        // If the first byte starts with a high nibble 0 (g or G), return error
+        if index == 0 && value == 0x10u8 {
+            return Err(AzamError::new(AzamErrorKind::LeadingZero).with_byte(*byte));
+        }
+        // If current nybble is a low nybble, this is the last one
+        if value >> 4 == 0x00u8 {
+            return Ok(index + 1);
+        }
+    }
+    Err(AzamError::new(AzamErrorKind::Truncated).at(value.len(), 0))
+}
+
+/// Pack `count` nybbles to `dst`, which must be exactly `count.div_ceil(2)` bytes long.
+fn pack_nybbles<I: Iterator<Item = u8>>(count: usize, mut nybbles: I, dst: &mut [u8]) {
+    let mut bytes = dst.iter_mut();
+    // If nybble count is odd, then the first byte only has a low nybble.
+    if count % 2 == 1 {
+        if let (Some(byte), Some(low)) = (bytes.next(), nybbles.next()) {
+            *byte = low;
+        }
+    }
+    for byte in bytes {
+        let high = nybbles.next().unwrap_or_default();
+        let low = nybbles.next().unwrap_or_default();
+        *byte = (high << 4) | low;
+    }
+}
+
+/// Decode `count` validated symbols of `value` to `dst`, which must be exactly `count.div_ceil(2)` bytes long.
+fn decode_into(value: &[u8], count: usize, dst: &mut [u8]) {
+    let nybbles = value[..count]
+        .iter()
+        .map(|byte| nybble_value(*byte).unwrap_or_default() & 0x0fu8);
+    pack_nybbles(count, nybbles, dst);
+}
+
+/// Given Azam codec encoded bytes, decode the first section and write to `dst` byte slice.
+/// Returns a tuple of number of bytes read from `value` and number of bytes written to `dst`.
+/// Decoded length is at most half of the read length rounded up.
+///
+/// # Arguments
+///
+/// * `value` - Azam codec encoded bytes
+/// * `dst` - Byte slice to write decoded bytes
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::decode::azam_decode_slice;
+///
+/// let mut dst = [0u8; 8];
+/// let (read, written) = azam_decode_slice(b"xytxvyyfh5wgg1", &mut dst).unwrap(); // (8, 4)
+/// let decoded = u32::from_be_bytes(dst[..written].try_into().unwrap()); // 0xdeadbeefu32
+/// ```
+pub fn azam_decode_slice(value: &[u8], dst: &mut [u8]) -> Result<(usize, usize)> {
+    let count = section_len(value)?;
+    let len = count.div_ceil(2);
+    if dst.len() < len {
+        return Err(AzamErrorKind::BufferTooSmall.into());
+    }
+    decode_into(value, count, &mut dst[..len]);
+    Ok((count, len))
+}
+
+/// Given Azam codec encoded bytes, decode the first section and append to `dst` byte vector.
+/// Returns a tuple of number of bytes read from `value` and number of bytes appended to `dst`.
+///
+/// # Arguments
+///
+/// * `value` - Azam codec encoded bytes
+/// * `dst` - Byte vector to append decoded bytes
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::decode::azam_decode_slice_to_vec;
+///
+/// let mut dst = Vec::<u8>::new();
+/// azam_decode_slice_to_vec("xytxvyyfh5wgg1", &mut dst).unwrap(); // (8, 4)
+/// // dst = vec![0xde, 0xad, 0xbe, 0xef]
+/// ```
+#[cfg(feature = "alloc")]
+pub fn azam_decode_slice_to_vec<T: AsRef<[u8]>>(
+    value: T,
+    dst: &mut Vec<u8>,
+) -> Result<(usize, usize)> {
+    let value = value.as_ref();
+    let count = section_len(value)?;
+    let len = count.div_ceil(2);
+    let start = dst.len();
+    dst.resize(start + len, 0);
+    decode_into(value, count, &mut dst[start..]);
+    Ok((count, len))
+}
+
+/// Returns the number of symbols and decoded bytes of the byte-string section at the start of given Azam codec encoded bytes.
+fn byte_string_section_len(value: &[u8]) -> Result<(usize, usize)> {
+    let count = section_len(value)?;
+    // Section must be a high nybble 1 marker, 2 high nybbles for each byte, and a low nybble 0
+    if count % 2 == 1
+        || nybble_value(value[0]) != Some(0x11u8)
+        || nybble_value(value[count - 1]) != Some(0x00u8)
+    {
+        return Err(AzamErrorKind::InvalidSection.into());
+    }
+    Ok((count, count / 2 - 1))
+}
+
+/// Decode validated byte-string section of `value` to `dst`, which must be exactly the decoded length.
+fn decode_byte_string_into(value: &[u8], dst: &mut [u8]) {
+    for (byte, symbols) in dst.iter_mut().zip(value[1..].chunks_exact(2)) {
+        let high = nybble_value(symbols[0]).unwrap_or_default() & 0x0fu8;
+        let low = nybble_value(symbols[1]).unwrap_or_default() & 0x0fu8;
+        *byte = (high << 4) | low;
+    }
+}
+
+/// Given Azam codec encoded bytes, decode the first section as a byte-string section and write to `dst` byte slice.
+/// Byte-string sections keep leading zero bytes and the exact length of byte array, see [`azam_encode_byte_string_slice`](crate::encode::azam_encode_byte_string_slice).
+/// Returns a tuple of number of bytes read from `value` and number of bytes written to `dst`.
+///
+/// # Arguments
+///
+/// * `value` - Azam codec encoded bytes
+/// * `dst` - Byte slice to write decoded bytes
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::decode::azam_decode_byte_string_slice;
+///
+/// let mut dst = [0u8; 8];
+/// let (read, written) = azam_decode_byte_string_slice(b"hgghn0wgg1", &mut dst).unwrap(); // (6, 2)
+/// // dst[..written] = [0x00, 0x15]
+/// ```
+pub fn azam_decode_byte_string_slice(value: &[u8], dst: &mut [u8]) -> Result<(usize, usize)> {
+    let (count, len) = byte_string_section_len(value)?;
+    if dst.len() < len {
+        return Err(AzamErrorKind::BufferTooSmall.into());
+    }
+    decode_byte_string_into(value, &mut dst[..len]);
+    Ok((count, len))
+}
+
+/// Given Azam codec encoded bytes, decode the first section as a byte-string section and append to `dst` byte vector.
+/// Byte-string sections keep leading zero bytes and the exact length of byte array, see [`azam_encode_byte_string_slice`](crate::encode::azam_encode_byte_string_slice).
+/// Returns a tuple of number of bytes read from `value` and number of bytes appended to `dst`.
+///
+/// # Arguments
+///
+/// * `value` - Azam codec encoded bytes
+/// * `dst` - Byte vector to append decoded bytes
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::decode::azam_decode_byte_string_to_vec;
+///
+/// let mut dst = Vec::<u8>::new();
+/// azam_decode_byte_string_to_vec("hgghn0wgg1", &mut dst).unwrap(); // (6, 2)
+/// // dst = vec![0x00, 0x15]
+/// ```
+#[cfg(feature = "alloc")]
+pub fn azam_decode_byte_string_to_vec<T: AsRef<[u8]>>(
+    value: T,
+    dst: &mut Vec<u8>,
+) -> Result<(usize, usize)> {
+    let value = value.as_ref();
+    let (count, len) = byte_string_section_len(value)?;
+    let start = dst.len();
+    dst.resize(start + len, 0);
+    decode_byte_string_into(value, &mut dst[start..]);
+    Ok((count, len))
+}
+
+/// Read a byte-string section from reader and append to `dst` byte vector.
+/// Returns the number of bytes appended to `dst`. Nothing is appended if the section is invalid.
+#[cfg(feature = "std")]
+pub(crate) fn read_byte_string<R: Read>(
+    reader: &mut AzamReader<R>,
+    dst: &mut Vec<u8>,
+) -> Result<usize> {
+    let (offset, section) = (reader.offset(), reader.section());
+    let start = dst.len();
+    match read_byte_string_into(reader, dst) {
+        Ok(true) => Ok(dst.len() - start),
+        result => {
+            dst.truncate(start);
+            result?;
+            Err(AzamError::new(AzamErrorKind::InvalidSection).at(offset, section))
+        }
+    }
+}
+
+/// Read a byte-string section from reader and append to `dst` byte vector.
+/// Returns false if the section is not a valid byte-string section, in which case the whole section is consumed.
+#[cfg(feature = "std")]
+fn read_byte_string_into<R: Read>(reader: &mut AzamReader<R>, dst: &mut Vec<u8>) -> Result<bool> {
+    // Section must start with a high nybble 1 marker
+    if reader.read_nybble()? != 0x11u8 {
+        reader.skip_section()?;
+        return Ok(false);
+    }
+    loop {
+        let high = reader.read_nybble()?;
+        // Section must end with a low nybble 0, right after a complete byte
+        if high >> 4 == 0x00u8 {
+            return Ok(high == 0x00u8);
+        }
+        let low = reader.read_nybble()?;
+        if low >> 4 == 0x00u8 {
+            return Ok(false);
+        }
+        dst.push(((high & 0x0fu8) << 4) | (low & 0x0fu8));
+    }
+}
+
 /// Given a source of a [`Read`] instance of Azam coded encoded stream, read all bytes up to EOF or end of first section, decode and write to [`Write`] instance.
 /// This method always write at least one byte, or throw an error.
 ///
 /// # Arguments
-
+///
 /// * `reader` - Azam codec encoded stream
 /// * `writer` - Stream to write decoded bytes
 ///
@@ -94,55 +361,12 @@ pub fn azam_decode_read_until<R: Read + ?Sized, W: Write>(
 /// azam_decode_read(&mut src, &mut dst).unwrap();
 /// let decoded = u32::from_be_bytes(dst.as_slice()[..4].try_into().unwrap()); // 0xdeadbeefu32
 /// ```
+#[cfg(feature = "std")]
 pub fn azam_decode_read<R: Read + ?Sized, W: Write>(
     reader: &mut R,
     writer: &mut W,
 ) -> Result<usize> {
-    let mut bytes = Vec::<u8>::with_capacity(INITIAL_CAPACITY);
-    let mut byte = [0u8; 1];
-    let mut prev_nybble = 0u8;
-    let mut is_odd = false;
-    let mut lead_nybble_checked = false;
-    let mut count = 0usize;
-    loop {
-        // Read one byte, or exit loop if read fails (EOF etc)
-        reader.read_exact(&mut byte)?;
-        count += 1;
-        // Use ok_or_else instead ok_or, because ok_or eagerly evaluates the value, which performance is significant at this level
-        let value = nybble_value(byte[0]).ok_or_else(|| Error::from(ErrorKind::InvalidData))?;
-        // Flip oddness
-        is_odd = !is_odd;
-        if !lead_nybble_checked {
-            // If the first byte starts with a high nibble 0 (g or G), return error as invalid data
-            if value == 0x10u8 {
-                return Err(ErrorKind::InvalidData.into());
-            }
-            lead_nybble_checked = true;
-        }
-        // Take previous nybble, shift left 4 and bit or current nybble
-        if !is_odd {
-            bytes.push((prev_nybble << 4) | (value & 0x0fu8));
-        }
-        // Remember current nybble for next iteration
-        prev_nybble = value & 0x0fu8;
-        // If current nybble is a low nybble, this is the last one, so exit loop
-        if value >> 4 == 00u8 {
-            break;
-        }
-    }
-    // If nybble count is odd, then there is one unwritten nybble.
-    // Add the unwritten nybble, and shift whole byte array 4 bits to the right.
-    if is_odd && count > 0 {
-        bytes.push(prev_nybble << 4);
-        let mut high_nybble = 0u8;
-        for i in 0..bytes.len() {
-            let byte = bytes[i];
-            bytes[i] = byte >> 4 | high_nybble;
-            high_nybble = byte << 4;
-        }
-    }
-    writer.write_all(&bytes)?;
-    Ok(count)
+    AzamReader::new(reader).read_section(writer)
 }
 
 /// Given a source of a [`&str`] instance of Azam coded encoded stream, read all bytes up to EOF or end of first section, decode and return as byte array.
@@ -160,9 +384,10 @@ pub fn azam_decode_read<R: Read + ?Sized, W: Write>(
 /// let mut dst = Vec::<u8>::new();
 /// let decoded = azam_decode_bytes("xytxvyyfh5wgg1").unwrap(); // vec![0xde, 0xad, 0xbe, 0xef]
 /// ```
+#[cfg(feature = "alloc")]
 pub fn azam_decode_bytes(value: &str) -> Result<Vec<u8>> {
     let mut bytes = Vec::<u8>::new();
-    azam_decode_read(&mut value.as_bytes(), &mut bytes)?;
+    azam_decode_slice_to_vec(value, &mut bytes)?;
     Ok(bytes)
 }
 
@@ -182,6 +407,7 @@ pub fn azam_decode_bytes(value: &str) -> Result<Vec<u8>> {
 /// let mut dst = Vec::<u8>::new();
 /// let decoded = azam_decode_bytes_until("xytxvyyfh5wgg1", 8).unwrap(); // vec![0xde, 0xad, 0xbe, 0xef]
 /// ```
+#[cfg(feature = "std")]
 pub fn azam_decode_bytes_until(value: &str, limit: u64) -> Result<Vec<u8>> {
     let mut bytes = Vec::<u8>::new();
     azam_decode_read_until(&mut value.as_bytes(), &mut bytes, limit)?;
@@ -205,27 +431,261 @@ pub fn azam_decode_bytes_until(value: &str, limit: u64) -> Result<Vec<u8>> {
 /// // decoded[1] = vec![0x15]
 /// // decoded[2] = vec![0xc0, 0x01]
 /// ```
+#[cfg(feature = "alloc")]
 pub fn azam_decode_bytes_vec(value: &str) -> Result<Vec<Vec<u8>>> {
     let mut all_bytes = Vec::<Vec<u8>>::new();
     let mut index = 0usize;
     while index < value.len() {
         let mut bytes = Vec::<u8>::new();
-        let mut reader = &value.as_bytes()[index..];
-        let read_size = azam_decode_read(&mut reader, &mut bytes)?;
+        let (read_size, _) = azam_decode_slice_to_vec(&value.as_bytes()[index..], &mut bytes)
+            .map_err(|err| {
+                let offset = index + err.offset();
+                err.at(offset, all_bytes.len()).with_input(value)
+            })?;
         all_bytes.push(bytes);
         index += read_size;
     }
     Ok(all_bytes)
 }
 
+/// Reader of Azam codec encoded stream, which reads one nybble at a time and keeps track of its position.
+/// All [`AzamDecode`] implementations read through this type, so errors can report byte offset and section index.
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::decode::{AzamDecode, AzamReader};
+///
+/// let mut reader = AzamReader::new("xytxvyyfh5wgg1".as_bytes());
+/// let x = u32::azam_decode_from(&mut reader).unwrap(); // 0xdeadbeefu32
+/// let y = u8::azam_decode_from(&mut reader).unwrap(); // 0x15u8
+/// let offset = reader.offset(); // 10
+/// let section = reader.section(); // 2
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct AzamReader<R> {
+    inner: R,
+    offset: usize,
+    section: usize,
+    symbols: usize,
+    options: DecodeOptions,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> AzamReader<R> {
+    /// Create a new reader from a [`Read`] instance of Azam codec encoded stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - Azam codec encoded stream
+    pub fn new(inner: R) -> Self {
+        Self::with_options(inner, DecodeOptions::LENIENT)
+    }
+
+    /// Create a new reader from a [`Read`] instance of Azam codec encoded stream, which reads with given options.
+    /// Limits of options are checked while reading, see [`DecodeOptions`].
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - Azam codec encoded stream
+    /// * `options` - Options of reading
+    pub fn with_options(inner: R, options: DecodeOptions) -> Self {
+        Self {
+            inner,
+            offset: 0,
+            section: 0,
+            symbols: 0,
+            options,
+        }
+    }
+
+    /// Returns the options of this reader.
+    pub fn options(&self) -> &DecodeOptions {
+        &self.options
+    }
+
+    /// Reject symbols that are not canonical, e.g. aliases and uppercase letters, with an [`AzamErrorKind::NonCanonical`] error.
+    /// Only canonical strings are accepted, so decoded values have a single encoded form, see [`canonical`](crate::canonical).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use azamcodec::decode::{AzamDecode, AzamReader};
+    ///
+    /// let mut reader = AzamReader::new("XYTXVYYF".as_bytes()).strict();
+    /// assert!(u32::azam_decode_from(&mut reader).is_err());
+    /// ```
+    pub fn strict(mut self) -> Self {
+        self.options = self.options.strict(true);
+        self
+    }
+
+    /// Returns the number of bytes read so far.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the index of the current section, which is the number of sections read so far.
+    pub fn section(&self) -> usize {
+        self.section
+    }
+
+    /// Returns the underlying [`Read`] instance.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read the next nybble of the current section.
+    /// Returns the nybble value [0..15]. If nybble is a high nybble, 16 will be added to the value, which means the section continues.
+    pub fn read_nybble(&mut self) -> Result<u8> {
+        let mut byte = self.read_byte()?;
+        // Separators are only allowed between sections
+        if self.symbols == 0 && self.section > 0 {
+            while self.options.separators.contains(&byte) {
+                self.offset += 1;
+                byte = self.read_byte()?;
+            }
+        }
+        // Use ok_or_else instead ok_or, because ok_or eagerly evaluates the value, which performance is significant at this level
+        let value = nybble_value(byte)
+            .ok_or_else(|| self.error(AzamErrorKind::InvalidSymbol).with_byte(byte))?;
+        if self.symbols == 0 {
+            // If the section starts with a high nibble 0 (g or G), return error
+            if value == 0x10u8 {
+                return Err(self.error(AzamErrorKind::LeadingZero).with_byte(byte));
+            }
+            if self
+                .options
+                .max_sections
+                .is_some_and(|max| self.section >= max)
+            {
+                return Err(self.error(AzamErrorKind::LimitExceeded).with_byte(byte));
+            }
+        }
+        if self
+            .options
+            .max_section_len
+            .is_some_and(|max| self.symbols >= max)
+        {
+            return Err(self.error(AzamErrorKind::LimitExceeded).with_byte(byte));
+        }
+        if self.options.strict && canonical_symbol(byte) != Some(byte) {
+            return Err(self.error(AzamErrorKind::NonCanonical).with_byte(byte));
+        }
+        self.offset += 1;
+        if value >> 4 == 0x00u8 {
+            // Low nybble is the last one of the section
+            self.section += 1;
+            self.symbols = 0;
+        } else {
+            self.symbols += 1;
+        }
+        Ok(value)
+    }
+
+    /// Read the next byte of the underlying stream, unless it exceeds the maximum input length.
+    fn read_byte(&mut self) -> Result<u8> {
+        if self
+            .options
+            .max_input_len
+            .is_some_and(|max| self.offset >= max)
+        {
+            return Err(self.error(AzamErrorKind::LimitExceeded));
+        }
+        let mut byte = [0u8; 1];
+        self.inner
+            .read_exact(&mut byte)
+            .map_err(|err| AzamError::from(err).at(self.offset, self.section))?;
+        Ok(byte[0])
+    }
+
+    /// Skip the rest of the current section, so the reader is left at a section boundary.
+    /// Does nothing if the reader is already at a section boundary.
+    pub fn skip_section(&mut self) -> Result<()> {
+        while self.symbols > 0 {
+            self.read_nybble()?;
+        }
+        Ok(())
+    }
+
+    /// Read a whole section, decode and write to `writer`, which is bounded by the options of this reader.
+    /// Returns the number of symbols read. Nybbles of the section are buffered, because the first byte only has a low nybble if the number of symbols is odd.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Stream to write decoded bytes
+    ///
+    /// # Examples
+    /// ```rust
+    /// use azamcodec::options::DecodeOptions;
+    ///
+    /// let mut reader = DecodeOptions::UNTRUSTED.separators(b"-").reader("xytxvyyf-h5".as_bytes());
+    /// let mut dst = Vec::<u8>::new();
+    /// reader.read_section(&mut dst).unwrap(); // 8
+    /// reader.read_section(&mut dst).unwrap(); // 2
+    /// // dst = vec![0xde, 0xad, 0xbe, 0xef, 0x15]
+    /// ```
+    pub fn read_section<W: Write>(&mut self, writer: &mut W) -> Result<usize> {
+        let mut nybbles = Vec::<u8>::with_capacity(INITIAL_CAPACITY * 2);
+        loop {
+            let value = self.read_nybble()?;
+            nybbles.push(value & 0x0fu8);
+            // If current nybble is a low nybble, this is the last one, so exit loop
+            if value >> 4 == 0x00u8 {
+                break;
+            }
+        }
+        let count = nybbles.len();
+        // Pack nybbles in place, each byte is written at or before the nybbles it is packed from.
+        // If nybble count is odd, then the first byte only has a low nybble.
+        let odd = count % 2;
+        for index in odd..count.div_ceil(2) {
+            nybbles[index] = (nybbles[index * 2 - odd] << 4) | nybbles[index * 2 + 1 - odd];
+        }
+        nybbles.truncate(count.div_ceil(2));
+        writer
+            .write_all(&nybbles)
+            .map_err(|err| AzamError::from(err).at(self.offset, self.section))?;
+        Ok(count)
+    }
+
+    /// Check that the underlying stream has no more bytes, or return an [`AzamErrorKind::TrailingData`] error at the current position.
+    pub(crate) fn finish(&mut self) -> Result<()> {
+        let mut byte = [0u8; 1];
+        match self.inner.read(&mut byte) {
+            Ok(0) => Ok(()),
+            Ok(_) => Err(self.error(AzamErrorKind::TrailingData).with_byte(byte[0])),
+            Err(err) => Err(AzamError::from(err).at(self.offset, self.section)),
+        }
+    }
+
+    /// Create an error of given kind at the current position.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Kind of error
+    pub fn error(&self, kind: AzamErrorKind) -> AzamError {
+        AzamError::new(kind).at(self.offset, self.section)
+    }
+}
+
 /// Trait to extend types to support decoding of Azam encoded strings.
+#[cfg(feature = "std")]
 pub trait AzamDecode: Sized {
+    /// Given an [`AzamReader`] instance of Azam codec encoded stream, read nybbles as needed, decode and return the correct representation of own object.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Azam codec encoded stream reader
+    fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self>;
+
     /// Given a source of a [`Read`] instance of Azam codec encoded stream, read bytes as needed, decode and return the correct representation of own object.
     ///
     /// # Arguments
     ///
     /// * `reader` - Azam codec encoded stream
-    fn azam_decode_read<R: Read + Sized>(reader: &mut R) -> Result<Self>;
+    fn azam_decode_read<R: Read + Sized>(reader: &mut R) -> Result<Self> {
+        Self::azam_decode_from(&mut AzamReader::new(reader))
+    }
 
     /// Given a source of a [`&str`] instance of Azam codec encoded stream, read bytes as needed, decode and return the correct representation of own object.
     ///
@@ -233,48 +693,546 @@ pub trait AzamDecode: Sized {
     ///
     /// * `value` - Azam codec encoded string
     fn azam_decode(value: &str) -> Result<Self> {
-        Self::azam_decode_read(&mut value.as_bytes())
+        DecodeOptions::LENIENT.decode(value)
+    }
+
+    /// Given a source of a [`&str`] instance of Azam codec encoded stream, decode and return the correct representation of own object.
+    /// Unlike [`AzamDecode::azam_decode`], the whole string must be consumed, and unconsumed input is rejected with an [`AzamErrorKind::TrailingData`] error at the offset where it begins.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Azam codec encoded string
+    ///
+    /// # Examples
+    /// ```rust
+    /// use azamcodec::decode::AzamDecode;
+    ///
+    /// let x = u32::azam_decode_exact("xytxvyyf").unwrap(); // 0xdeadbeefu32
+    /// let err = u32::azam_decode_exact("xytxvyyfJUNK").unwrap_err(); // trailing data 'J' at offset 8
+    /// ```
+    fn azam_decode_exact(value: &str) -> Result<Self> {
+        DecodeOptions::EXACT.decode(value)
+    }
+
+    /// Given a source of a [`&str`] instance of Azam codec encoded stream, decode and return the correct representation of own object.
+    /// Unlike [`AzamDecode::azam_decode`], symbols must be canonical, and aliases and uppercase letters are rejected with an [`AzamErrorKind::NonCanonical`] error.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Azam codec encoded string
+    ///
+    /// # Examples
+    /// ```rust
+    /// use azamcodec::decode::AzamDecode;
+    ///
+    /// let x = u32::azam_decode_strict("xytxvyyf").unwrap(); // 0xdeadbeefu32
+    /// assert!(u32::azam_decode_strict("XYTXVYYF").is_err());
+    /// ```
+    fn azam_decode_strict(value: &str) -> Result<Self> {
+        DecodeOptions::STRICT.decode(value)
+    }
+
+    /// Decode a sequence of values, which is used by implementations of arrays and vectors.
+    /// Each value is preceded by a single low nybble 1 section, and the sequence ends with a single low nybble 0 section.
+    /// Types may override this to use a more compact encoding, e.g. [`u8`] uses a byte-string section.
+    #[doc(hidden)]
+    fn azam_decode_seq_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Vec<Self>> {
+        let mut values = Vec::<Self>::new();
+        while bool::azam_decode_from(reader)? {
+            values.push(Self::azam_decode_from(reader)?);
+        }
+        Ok(values)
     }
 }
 
-impl AzamDecode for u8 {
-    fn azam_decode_read<R: Read + Sized>(reader: &mut R) -> Result<Self> {
-        let mut bytes = Vec::<u8>::new();
-        // Limit to twice the byte size of type
-        azam_decode_read_until(reader, &mut bytes, 2)?;
-        // This is safe because decode will always write at least one byte or error out.
-        Ok(u8::from_be_bytes(bytes[..1].try_into().unwrap()))
+/// Trait to extend integer types to support lossy decoding of Azam encoded strings, which sections may not fit into the type.
+/// Unlike [`AzamDecode`], which returns an [`AzamErrorKind::Overflow`] error, out of range values are either saturated or truncated.
+/// In both cases, the whole section is consumed.
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::decode::AzamDecodeLossy;
+///
+/// let x = u8::azam_decode_saturating("hgg0").unwrap(); // 0xffu8
+/// let y = u8::azam_decode_truncating("hgg0").unwrap(); // 0x00u8
+/// ```
+#[cfg(feature = "std")]
+pub trait AzamDecodeLossy: AzamDecode {
+    /// Given an [`AzamReader`] instance of Azam codec encoded stream, read the whole section, decode and return the value clamped to the range of own type.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Azam codec encoded stream reader
+    fn azam_decode_saturating_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self>;
+
+    /// Given an [`AzamReader`] instance of Azam codec encoded stream, read the whole section, decode and return the low bits of the value that fit into own type.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Azam codec encoded stream reader
+    fn azam_decode_truncating_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self>;
+
+    /// Given a source of a [`&str`] instance of Azam codec encoded stream, read the whole section, decode and return the value clamped to the range of own type.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Azam codec encoded string
+    fn azam_decode_saturating(value: &str) -> Result<Self> {
+        Self::azam_decode_saturating_from(&mut AzamReader::new(value.as_bytes()))
+            .map_err(|err| err.with_input(value))
+    }
+
+    /// Given a source of a [`&str`] instance of Azam codec encoded stream, read the whole section, decode and return the low bits of the value that fit into own type.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Azam codec encoded string
+    fn azam_decode_truncating(value: &str) -> Result<Self> {
+        Self::azam_decode_truncating_from(&mut AzamReader::new(value.as_bytes()))
+            .map_err(|err| err.with_input(value))
     }
 }
 
+/// Read a whole section of unsigned integer from reader.
+/// Returns the low bits of the value that fit into the type, and whether the value overflowed.
+#[cfg(feature = "std")]
+macro_rules! azam_read_uint {
+    ($reader:expr, $t:ty) => {{
+        let mut value: $t = 0;
+        let mut count = 0usize;
+        loop {
+            let nybble = $reader.read_nybble()?;
+            // Nybbles beyond the width of type are shifted out
+            value = (value << 4) | (nybble & 0x0fu8) as $t;
+            count += 1;
+            // If current nybble is a low nybble, this is the last one
+            if nybble >> 4 == 0x00u8 {
+                break;
+            }
+        }
+        // Sections never start with a high nybble 0, so any section longer than twice the byte size of type overflows
+        (value, count > <$t>::BITS as usize / 4)
+    }};
+}
+
 macro_rules! azam_decode_uint_impl {
-    ($t:ty, $s:expr) => {
+    ($t:ty $({ $($extra:tt)* })?) => {
+        #[cfg(feature = "std")]
         impl AzamDecode for $t {
-            fn azam_decode_read<R: Read + Sized>(reader: &mut R) -> Result<Self> {
-                // Saving one call instead of calling below
-                // let size = mem::size_of::<Self>();
-                const SIZE: usize = $s;
-                let mut bytes = Vec::<u8>::new();
+            fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+                let (offset, section) = (reader.offset(), reader.section());
+                match azam_read_uint!(reader, $t) {
+                    (value, false) => Ok(value),
+                    (_, true) => Err(AzamError::new(AzamErrorKind::Overflow).at(offset, section)),
+                }
+            }
+
+            #[inline]
+            fn azam_decode(value: &str) -> Result<Self> {
+                let bytes = value.as_bytes();
+                let mut result: $t = 0;
                 // Limit to twice the byte size of type
-                $crate::decode::azam_decode_read_until(reader, &mut bytes, (SIZE * 2) as u64)?;
-                // Extend byte array to byte size of type and prepend with zeroes
-                let original_len = bytes.len();
-                if original_len < SIZE {
-                    bytes.resize(SIZE, 0);
-                    bytes.rotate_right(SIZE - original_len);
+                for (index, byte) in bytes.iter().take(<$t>::BITS as usize / 4).enumerate() {
+                    let nybble = NYBBLE_VALUES[*byte as usize];
+                    result = (result << 4) | (nybble & 0x0fu8) as Self;
+                    // If current nybble is not a high nybble, this is either the last one or an invalid symbol
+                    if nybble & 0xf0u8 != 0x10u8 {
+                        return match nybble {
+                            0xffu8 => Err(AzamError::new(AzamErrorKind::InvalidSymbol)
+                                .at(index, 0)
+                                .with_byte(*byte)
+                                .with_input(value)),
+                            _ => Ok(result),
+                        };
+                    }
+                    // If the first byte starts with a high nibble 0 (g or G), return error
+                    if index == 0 && nybble == 0x10u8 {
+                        return Err(AzamError::new(AzamErrorKind::LeadingZero)
+                            .with_byte(*byte)
+                            .with_input(value));
+                    }
+                }
+                if bytes.len() <= <$t>::BITS as usize / 4 {
+                    return Err(AzamError::new(AzamErrorKind::Truncated)
+                        .at(bytes.len(), 0)
+                        .with_input(value));
+                }
+                // Section is wider than type, so read through the rest of it to tell overflow from invalid input
+                Self::azam_decode_from(&mut AzamReader::new(bytes))
+                    .map_err(|err| err.with_input(value))
+            }
+
+            $($($extra)*)?
+        }
+
+        #[cfg(feature = "std")]
+        impl AzamDecodeLossy for $t {
+            fn azam_decode_saturating_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+                match azam_read_uint!(reader, $t) {
+                    (value, false) => Ok(value),
+                    (_, true) => Ok(<$t>::MAX),
                 }
-                Ok(Self::from_be_bytes(bytes[..SIZE].try_into().unwrap()))
             }
+
+            fn azam_decode_truncating_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+                Ok(azam_read_uint!(reader, $t).0)
+            }
+        }
+    };
+}
+
+azam_decode_uint_impl!(u8 {
+    /// Decode a byte-string section, see [`azam_decode_byte_string_to_vec`].
+    fn azam_decode_seq_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Vec<Self>> {
+        let mut values = Vec::<u8>::new();
+        read_byte_string(reader, &mut values)?;
+        Ok(values)
+    }
+});
+azam_decode_uint_impl!(u16);
+azam_decode_uint_impl!(u32);
+azam_decode_uint_impl!(u64);
+azam_decode_uint_impl!(u128);
+azam_decode_uint_impl!(usize);
+
+/// Read an ordered section written by [`OrderedSection`](crate::encode::OrderedSection), and validate its header and minimal length.
+/// Returns whether the value is negative, the low 128 bits of payload and the payload nybble count.
+/// The whole section is consumed, even if it is invalid.
+#[cfg(feature = "std")]
+pub(crate) fn read_ordered_section<R: Read>(
+    reader: &mut AzamReader<R>,
+) -> Result<(bool, u128, usize)> {
+    let (offset, section) = (reader.offset(), reader.section());
+    let invalid = || AzamError::new(AzamErrorKind::InvalidSection).at(offset, section);
+    let high = reader.read_nybble()?;
+    if high >> 4 == 0x00u8 {
+        return Err(invalid());
+    }
+    let low = reader.read_nybble()?;
+    let header = ((high & 0x0fu8) << 4) | (low & 0x0fu8);
+    // Only zero has no payload
+    if low >> 4 == 0x00u8 {
+        return match header {
+            0x80u8 => Ok((false, 0, 0)),
+            _ => Err(invalid()),
+        };
+    }
+    let (negative, len) = match header {
+        0x80u8 => {
+            reader.skip_section()?;
+            return Err(invalid());
+        }
+        0x81u8.. => (false, (header - 0x80u8) as usize),
+        _ => (true, (0x80u8 - header) as usize),
+    };
+    let mut payload = 0u128;
+    for index in 0..len {
+        let nybble = reader.read_nybble()?;
+        if index == 0 {
+            // Most significant nybble must be significant, or the value would have a shorter encoding
+            let redundant = match negative {
+                false => nybble & 0x0fu8 == 0x00u8,
+                true => len > 1 && nybble & 0x0fu8 == 0x0fu8,
+            };
+            if redundant {
+                reader.skip_section()?;
+                return Err(invalid());
+            }
+        }
+        payload = (payload << 4) | (nybble & 0x0fu8) as u128;
+        // Section must end exactly after the last payload nybble
+        if (nybble >> 4 == 0x00u8) != (index == len - 1) {
+            reader.skip_section()?;
+            return Err(invalid());
+        }
+    }
+    Ok((negative, payload, len))
+}
+
+/// Convert an ordered section payload to [`i128`], or [`None`] if it does not fit.
+#[cfg(feature = "std")]
+fn ordered_to_i128(negative: bool, payload: u128, len: usize) -> Option<i128> {
+    match (negative, len) {
+        (_, 33..) => None,
+        // Full width payload must already have the correct sign bit
+        (_, 32) => Some(payload as i128).filter(|value| (*value < 0) == negative),
+        (false, _) => Some(payload as i128),
+        (true, _) => Some((payload | (u128::MAX << (len * 4))) as i128),
+    }
+}
+
+macro_rules! azam_decode_int_impl {
+    ($t:ty) => {
+        #[cfg(feature = "std")]
+        impl AzamDecode for $t {
+            fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+                let (offset, section) = (reader.offset(), reader.section());
+                let (negative, payload, len) = read_ordered_section(reader)?;
+                ordered_to_i128(negative, payload, len)
+                    .and_then(|value| <$t>::try_from(value).ok())
+                    .ok_or_else(|| AzamError::new(AzamErrorKind::Overflow).at(offset, section))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl AzamDecodeLossy for $t {
+            fn azam_decode_saturating_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+                let (negative, payload, len) = read_ordered_section(reader)?;
+                let value = ordered_to_i128(negative, payload, len)
+                    .and_then(|value| <$t>::try_from(value).ok());
+                Ok(match (value, negative) {
+                    (Some(value), _) => value,
+                    (None, false) => <$t>::MAX,
+                    (None, true) => <$t>::MIN,
+                })
+            }
+
+            fn azam_decode_truncating_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+                let (negative, payload, len) = read_ordered_section(reader)?;
+                // Sign extend payload, unless it already covers all 128 bits
+                let value = match (negative, len) {
+                    (true, 0..32) => payload | (u128::MAX << (len * 4)),
+                    _ => payload,
+                };
+                Ok(value as $t)
+            }
+        }
+    };
+}
+
+azam_decode_int_impl!(i8);
+azam_decode_int_impl!(i16);
+azam_decode_int_impl!(i32);
+azam_decode_int_impl!(i64);
+azam_decode_int_impl!(i128);
+azam_decode_int_impl!(isize);
+
+macro_rules! azam_decode_float_impl {
+    ($t:ty, $u:ty) => {
+        #[cfg(feature = "std")]
+        impl AzamDecode for $t {
+            fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+                let (offset, section) = (reader.offset(), reader.section());
+                let key = match read_ordered_section(reader)? {
+                    (true, _, _) => {
+                        return Err(
+                            AzamError::new(AzamErrorKind::InvalidSection).at(offset, section)
+                        )
+                    }
+                    // Payload of a longer section would have lost its high bits
+                    (false, payload, len) if len <= <$u>::BITS as usize / 4 => payload as $u,
+                    (false, _, _) => {
+                        return Err(AzamError::new(AzamErrorKind::Overflow).at(offset, section))
+                    }
+                };
+                // Reverse the key transformation, see `AzamEncode` implementation of floats
+                let sign: $u = !(<$u>::MAX >> 1);
+                let bits = match key & sign {
+                    0 => !key,
+                    _ => key ^ sign,
+                };
+                Ok(<$t>::from_bits(bits))
+            }
+        }
+    };
+}
+
+azam_decode_float_impl!(f32, u32);
+azam_decode_float_impl!(f64, u64);
+
+#[cfg(feature = "std")]
+impl AzamDecode for bool {
+    fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+        let (offset, section) = (reader.offset(), reader.section());
+        match reader.read_nybble()? {
+            0x00u8 => Ok(false),
+            0x01u8 => Ok(true),
+            _ => {
+                reader.skip_section()?;
+                Err(AzamError::new(AzamErrorKind::InvalidSection).at(offset, section))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl AzamDecode for char {
+    fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+        let (offset, section) = (reader.offset(), reader.section());
+        match read_ordered_section(reader)? {
+            // Surrogates and values beyond char::MAX are not Unicode scalar values
+            // Payload of a longer section would have lost its high bits
+            (false, payload, len) if len <= 8 => char::from_u32(payload as u32),
+            _ => None,
+        }
+        .ok_or_else(|| AzamError::new(AzamErrorKind::InvalidSection).at(offset, section))
+    }
+}
+
+macro_rules! azam_decode_non_zero_impl {
+    ($t:ty, $i:ty) => {
+        #[cfg(feature = "std")]
+        impl AzamDecode for $t {
+            fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+                let (offset, section) = (reader.offset(), reader.section());
+                let value = <$i>::azam_decode_from(reader)?;
+                <$t>::new(value).ok_or_else(|| {
+                    AzamError::new(AzamErrorKind::InvalidSection).at(offset, section)
+                })
+            }
+        }
+    };
+}
+
+azam_decode_non_zero_impl!(NonZeroU8, u8);
+azam_decode_non_zero_impl!(NonZeroU16, u16);
+azam_decode_non_zero_impl!(NonZeroU32, u32);
+azam_decode_non_zero_impl!(NonZeroU64, u64);
+azam_decode_non_zero_impl!(NonZeroU128, u128);
+azam_decode_non_zero_impl!(NonZeroUsize, usize);
+azam_decode_non_zero_impl!(NonZeroI8, i8);
+azam_decode_non_zero_impl!(NonZeroI16, i16);
+azam_decode_non_zero_impl!(NonZeroI32, i32);
+azam_decode_non_zero_impl!(NonZeroI64, i64);
+azam_decode_non_zero_impl!(NonZeroI128, i128);
+azam_decode_non_zero_impl!(NonZeroIsize, isize);
+
+/// Decode values each preceded by a single low nybble 1 section, until a single low nybble 0 section.
+/// Vectors of [`u8`] are decoded from a byte-string section instead, see [`azam_decode_byte_string_to_vec`].
+#[cfg(feature = "std")]
+impl<T: AzamDecode> AzamDecode for Vec<T> {
+    fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+        T::azam_decode_seq_from(reader)
+    }
+}
+
+/// Decode the same as vectors, see [`AzamDecode`] implementation of `Vec<T>`.
+#[cfg(feature = "std")]
+impl<T: AzamDecode> AzamDecode for Box<[T]> {
+    fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+        Vec::<T>::azam_decode_from(reader).map(Vec::into_boxed_slice)
+    }
+}
+
+/// Decode the same as vectors, which must have exactly `N` values, see [`AzamDecode`] implementation of `Vec<T>`.
+#[cfg(feature = "std")]
+impl<T: AzamDecode, const N: usize> AzamDecode for [T; N] {
+    fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+        let (offset, section) = (reader.offset(), reader.section());
+        Vec::<T>::azam_decode_from(reader)?
+            .try_into()
+            .map_err(|_| AzamError::new(AzamErrorKind::InvalidSection).at(offset, section))
+    }
+}
+
+/// Decode a byte-string section of UTF-8 bytes.
+#[cfg(feature = "std")]
+impl AzamDecode for String {
+    fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+        let (offset, section) = (reader.offset(), reader.section());
+        String::from_utf8(Vec::<u8>::azam_decode_from(reader)?)
+            .map_err(|_| AzamError::new(AzamErrorKind::InvalidUtf8).at(offset, section))
+    }
+}
+
+/// Decode a byte-string section of UTF-8 bytes.
+#[cfg(feature = "std")]
+impl AzamDecode for Box<str> {
+    fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+        String::azam_decode_from(reader).map(String::into_boxed_str)
+    }
+}
+
+/// Decode a single low nybble 0 section as [`None`], or a single low nybble 1 section followed by the value as [`Some`].
+#[cfg(feature = "std")]
+impl<T: AzamDecode> AzamDecode for Option<T> {
+    fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+        match bool::azam_decode_from(reader)? {
+            false => Ok(None),
+            true => T::azam_decode_from(reader).map(Some),
+        }
+    }
+}
+
+/// Decode nothing, which is useful as a placeholder in generic code.
+#[cfg(feature = "std")]
+impl AzamDecode for () {
+    fn azam_decode_from<R: Read>(_reader: &mut AzamReader<R>) -> Result<Self> {
+        Ok(())
+    }
+}
+
+/// Decode nothing, like `()`.
+#[cfg(feature = "std")]
+impl<T: ?Sized> AzamDecode for PhantomData<T> {
+    fn azam_decode_from<R: Read>(_reader: &mut AzamReader<R>) -> Result<Self> {
+        Ok(PhantomData)
+    }
+}
+
+/// Decode each element of tuple from consecutive sections.
+macro_rules! azam_decode_tuple_impl {
+    ($($name:ident)+) => {
+        #[cfg(feature = "std")]
+        impl<$($name: AzamDecode),+> AzamDecode for ($($name,)+) {
+            fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+                Ok(($($name::azam_decode_from(reader)?,)+))
+            }
+        }
+    };
+}
+
+azam_decode_tuple_impl!(A);
+azam_decode_tuple_impl!(A B);
+azam_decode_tuple_impl!(A B C);
+azam_decode_tuple_impl!(A B C D);
+azam_decode_tuple_impl!(A B C D E);
+azam_decode_tuple_impl!(A B C D E F);
+azam_decode_tuple_impl!(A B C D E F G);
+azam_decode_tuple_impl!(A B C D E F G H);
+azam_decode_tuple_impl!(A B C D E F G H I);
+azam_decode_tuple_impl!(A B C D E F G H I J);
+azam_decode_tuple_impl!(A B C D E F G H I J K);
+azam_decode_tuple_impl!(A B C D E F G H I J K L);
+
+/// Decode a byte-string section of complemented symbols, see [`AzamEncode`](crate::encode::AzamEncode) implementation of [`Reverse`].
+/// The section must contain exactly one encoded value.
+#[cfg(feature = "std")]
+impl<T: AzamDecode> AzamDecode for Reverse<T> {
+    fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+        let (offset, section) = (reader.offset(), reader.section());
+        let invalid = || AzamError::new(AzamErrorKind::InvalidSection).at(offset, section);
+        let mut symbols = Vec::<u8>::azam_decode_from(reader)?;
+        for symbol in symbols.iter_mut() {
+            *symbol = match 31u8.checked_sub(*symbol) {
+                Some(value @ 0x00..=0x0f) => crate::encode::LOWER_ALPHABETS[value as usize],
+                Some(value) => crate::encode::HIGHER_ALPHABETS[(value & 0x0fu8) as usize],
+                None => return Err(invalid()),
+            };
+        }
+        let mut inner = AzamReader::new(symbols.as_slice());
+        // Errors of inner value are reported at the start of section
+        let value = T::azam_decode_from(&mut inner).map_err(|err| match err.kind() {
+            AzamErrorKind::Overflow => AzamError::new(AzamErrorKind::Overflow).at(offset, section),
+            _ => invalid(),
+        })?;
+        match inner.into_inner().is_empty() {
+            true => Ok(Reverse(value)),
+            false => Err(invalid()),
         }
-    };
+    }
 }
 
-azam_decode_uint_impl!(u16, 2);
-azam_decode_uint_impl!(u32, 4);
-azam_decode_uint_impl!(u64, 8);
-azam_decode_uint_impl!(u128, 16);
+/// Out of range values are truncated to the low bits that fit into the type, as wrapping arithmetic would.
+#[cfg(feature = "std")]
+impl<T: AzamDecodeLossy> AzamDecode for Wrapping<T> {
+    fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+        T::azam_decode_truncating_from(reader).map(Wrapping)
+    }
+}
 
-/// Macro to decode Azam encoded string to tuples of any types that implements the [`AzamDecode`] trait
+/// Macro to decode Azam encoded string to tuples of any types that implements the [`AzamDecode`] trait.
+/// Up to 12 types are decoded as a tuple of the types, and more types are decoded one by one.
 ///
 /// # Examples
 ///
@@ -286,30 +1244,65 @@ azam_decode_uint_impl!(u128, 16);
 /// // "wgg1" decodes to 0xc001u16.
 /// let (x, y, z) = azam_decode!("xytxvyyfh5wgg1", u32, u8, u16).unwrap(); // (0xdeadbeefu32, 0x15u8, c001u16)
 /// ```
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! azam_decode {
-    () => {Result::<()>::Ok(())};
-    ($r:expr) => {Result::<()>::Ok(())};
-    ($r:expr $(,$t:ty)*) => {{
-        use $crate::decode::AzamDecode;
-        let reader = &mut $r.as_bytes();
-        // Using loop hack to not to use break-labels.
-        // This might help when using strict clippy rules.
-        // https://github.com/rust-lang/rfcs/pull/2046
-        loop {
-            break Ok((
-                $(
-                    match <$t>::azam_decode_read(reader) {
-                        Ok(v) => v,
-                        Err(e) => break Err(e),
-                    }
-                ),*
-            ));
+    () => {$crate::error::Result::<()>::Ok(())};
+    ($r:expr) => {$crate::error::Result::<()>::Ok(())};
+    ($r:expr, $t:ty) => {
+        <$t as $crate::decode::AzamDecode>::azam_decode(::core::convert::AsRef::<str>::as_ref(&$r))
+    };
+    // Tuples implement AzamDecode up to 12 elements, so longer lists are decoded type by type
+    ($r:expr, $t0:ty, $t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty, $t12:ty $(,$t:ty)*) => {
+        // Match keeps temporaries of the input expression alive
+        match ::core::convert::AsRef::<str>::as_ref(&$r) {
+            value => $crate::__azam_decode_each!(
+                &mut $crate::decode::AzamReader::new(value.as_bytes()), $t0, $t1, $t2, $t3, $t4, $t5, $t6, $t7, $t8, $t9, $t10, $t11, $t12 $(,$t)*
+            )
+            .map_err(|err| err.with_input(value)),
         }
-    }};
+    };
+    ($r:expr $(,$t:ty)+) => {
+        <($($t),+) as $crate::decode::AzamDecode>::azam_decode(::core::convert::AsRef::<str>::as_ref(&$r))
+    };
+}
+
+/// Macro to decode Azam encoded string to tuples of any types that implements the [`AzamDecode`] trait, which must consume the whole string.
+/// Unlike [`azam_decode!`], unconsumed input is rejected with an [`AzamErrorKind::TrailingData`](crate::error::AzamErrorKind::TrailingData) error at the offset where it begins.
+///
+/// # Examples
+///
+/// ```rust
+/// use azamcodec::azam_decode_exact;
+/// let (x, y, z) = azam_decode_exact!("xytxvyyfh5wgg1", u32, u8, u16).unwrap(); // (0xdeadbeefu32, 0x15u8, c001u16)
+/// let err = azam_decode_exact!("xytxvyyfh5wgg1JUNK", u32, u8, u16).unwrap_err(); // trailing data 'J' at offset 14
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! azam_decode_exact {
+    ($r:expr) => {
+        <() as $crate::decode::AzamDecode>::azam_decode_exact(::core::convert::AsRef::<str>::as_ref(&$r))
+    };
+    ($r:expr, $t:ty) => {
+        <$t as $crate::decode::AzamDecode>::azam_decode_exact(::core::convert::AsRef::<str>::as_ref(&$r))
+    };
+    ($r:expr, $t0:ty, $t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty, $t12:ty $(,$t:ty)*) => {
+        match ::core::convert::AsRef::<str>::as_ref(&$r) {
+            value => {
+                let mut reader = $crate::options::DecodeOptions::EXACT.reader(value.as_bytes());
+                $crate::__azam_decode_each!(&mut reader, $t0, $t1, $t2, $t3, $t4, $t5, $t6, $t7, $t8, $t9, $t10, $t11, $t12 $(,$t)*)
+                    .and_then(|values| $crate::__private::finish(&mut reader).map(|()| values))
+                    .map_err(|err| err.with_input(value))
+            }
+        }
+    };
+    ($r:expr $(,$t:ty)+) => {
+        <($($t),+) as $crate::decode::AzamDecode>::azam_decode_exact(::core::convert::AsRef::<str>::as_ref(&$r))
+    };
 }
 
 /// Macro to decode Azam codec encoded stream to tuples of any types that implements the [`AzamDecode`] trait.
+/// Up to 12 types are decoded as a tuple of the types, and more types are decoded one by one.
 ///
 /// # Examples
 ///
@@ -322,33 +1315,52 @@ macro_rules! azam_decode {
 /// let mut reader = &mut "xytxvyyfh5wgg1".as_bytes();
 /// let (x, y, z) = azam_decode_read!(&mut reader, u32, u8, u16).unwrap(); // (0xdeadbeefu32, 0x15u8, c001u16)
 /// ```
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! azam_decode_read {
-    () => {Result::<()>::Ok(())};
-    ($r:expr) => {Result::<()>::Ok(())};
-    ($r:expr $(,$t:ty)*) => {{
-        use $crate::decode::AzamDecode;
-        let reader = $r;
-        // Using loop hack to not to use break-labels.
-        // This might help when using strict clippy rules.
-        // https://github.com/rust-lang/rfcs/pull/2046
-        loop {
-            break Ok((
-                $(
-                    match <$t>::azam_decode_read(reader) {
-                        Ok(v) => v,
-                        Err(e) => break Err(e),
-                    }
-                ),*
-            ))
+    () => {$crate::error::Result::<()>::Ok(())};
+    ($r:expr) => {$crate::error::Result::<()>::Ok(())};
+    ($r:expr, $t:ty) => {
+        <$t as $crate::decode::AzamDecode>::azam_decode_from(&mut $crate::decode::AzamReader::new($r))
+    };
+    ($r:expr, $t0:ty, $t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty, $t12:ty $(,$t:ty)*) => {
+        $crate::__azam_decode_each!(&mut $crate::decode::AzamReader::new($r), $t0, $t1, $t2, $t3, $t4, $t5, $t6, $t7, $t8, $t9, $t10, $t11, $t12 $(,$t)*)
+    };
+    ($r:expr $(,$t:ty)+) => {
+        <($($t),+) as $crate::decode::AzamDecode>::azam_decode_from(&mut $crate::decode::AzamReader::new($r))
+    };
+}
+
+/// Decode each type from an [`AzamReader`] in turn, which is used by decoding macros for more types than tuples implement [`AzamDecode`] for.
+#[doc(hidden)]
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! __azam_decode_each {
+    ($reader:expr $(,$t:ty)+) => {
+        match $reader {
+            reader => {
+                // Using loop hack to not to use break-labels.
+                // This might help when using strict clippy rules.
+                // https://github.com/rust-lang/rfcs/pull/2046
+                loop {
+                    break $crate::error::Result::Ok(($(
+                        match <$t as $crate::decode::AzamDecode>::azam_decode_from(reader) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => break ::core::result::Result::Err(err),
+                        }
+                    ),+));
+                }
+            }
         }
-    }};
+    };
 }
 
 #[cfg(test)]
 mod tests {
     use crate::decode::*;
+    use crate::error::AzamErrorKind;
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_azam_decode_macro() {
         assert_eq!((), azam_decode!("123").unwrap());
@@ -358,24 +1370,139 @@ mod tests {
             (0x01u8, 0x02u16, 0x03u32),
             azam_decode!("123", u8, u16, u32).unwrap()
         );
+        // More types than tuples implement AzamDecode for
+        let (a, .., m) = azam_decode!(
+            "123456789abcd",
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u16
+        )
+        .unwrap();
+        assert_eq!((1u8, 13u16), (a, m));
+        let err = azam_decode!(
+            String::from("123456789abc"),
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8
+        )
+        .err()
+        .unwrap();
+        assert_eq!(AzamErrorKind::Truncated, err.kind());
+        assert_eq!(12, err.offset());
+        assert_eq!(Some("123456789abc"), err.input());
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_azam_decode_macro_err() {
         assert_eq!(
-            ErrorKind::UnexpectedEof,
+            AzamErrorKind::Truncated,
             azam_decode!("", u8, u16).unwrap_err().kind()
         );
         assert_eq!(
-            ErrorKind::UnexpectedEof,
+            AzamErrorKind::Truncated,
             azam_decode!("12", u8, u16, u32).unwrap_err().kind()
         );
         assert_eq!(
-            ErrorKind::InvalidData,
+            AzamErrorKind::InvalidSymbol,
             azam_decode!("_2", u8, u16).unwrap_err().kind()
         );
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_decode_exact_macro() {
+        assert_eq!((), azam_decode_exact!("").unwrap());
+        assert_eq!((0x01u8), azam_decode_exact!("1", u8).unwrap());
+        assert_eq!(
+            (0xdeadbeefu32, 0x15u8, 0xc001u16),
+            azam_decode_exact!("xytxvyyfh5wgg1", u32, u8, u16).unwrap()
+        );
+        assert_eq!(
+            (0x01u8, 0x02u16, 0x03u32),
+            azam_decode_exact!(String::from("123"), u8, u16, u32).unwrap()
+        );
+        let (.., n) = azam_decode_exact!(
+            "123456789abcdh0",
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8
+        )
+        .unwrap();
+        assert_eq!(0x10u8, n);
+        let err = azam_decode_exact!(
+            "123456789abcdh0",
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8
+        )
+        .err()
+        .unwrap();
+        assert_eq!(AzamErrorKind::TrailingData, err.kind());
+        assert_eq!(13, err.offset());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_decode_exact_macro_err() {
+        let err = azam_decode_exact!("123").unwrap_err();
+        assert_eq!(AzamErrorKind::TrailingData, err.kind());
+        assert_eq!(0, err.offset());
+        let err = azam_decode_exact!("xytxvyyfh5wgg1JUNK", u32, u8, u16).unwrap_err();
+        assert_eq!(AzamErrorKind::TrailingData, err.kind());
+        assert_eq!(14, err.offset());
+        assert_eq!(3, err.section());
+        assert_eq!(Some(b'J'), err.byte());
+        assert_eq!(
+            "trailing data 'J' at offset 14 in section 3\n  xytxvyyfh5wgg1JUNK\n                ^",
+            err.to_string()
+        );
+        let err = azam_decode_exact!("xytxvyyfh5wg", u32, u8, u16).unwrap_err();
+        assert_eq!(AzamErrorKind::Truncated, err.kind());
+    }
+
+    #[cfg(feature = "std")]
     #[test]
     fn test_azam_decode_read_macro() {
         assert_eq!((), azam_decode_read!("123").unwrap());
@@ -391,32 +1518,162 @@ mod tests {
             (0x01u8, 0x02u16, 0x03u32),
             azam_decode_read!(&mut "123".as_bytes(), u8, u16, u32).unwrap()
         );
+        let (.., m) = azam_decode_read!(
+            &mut "123456789abcd".as_bytes(),
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8
+        )
+        .unwrap();
+        assert_eq!(13u8, m);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_azam_decode_read_macro_err() {
         assert_eq!(
-            ErrorKind::UnexpectedEof,
+            AzamErrorKind::Truncated,
             azam_decode_read!(&mut "".as_bytes(), u8, u16)
                 .unwrap_err()
                 .kind()
         );
         assert_eq!(
-            ErrorKind::UnexpectedEof,
+            AzamErrorKind::Truncated,
             azam_decode_read!(&mut "12".as_bytes(), u8, u16, u32)
                 .unwrap_err()
                 .kind()
         );
         assert_eq!(
-            ErrorKind::InvalidData,
+            AzamErrorKind::InvalidSymbol,
             azam_decode_read!(&mut "_2".as_bytes(), u8, u16)
                 .unwrap_err()
                 .kind()
         );
     }
 
+    #[test]
+    fn test_azam_decode_slice() {
+        let mut dst = [0u8; 4];
+        assert_eq!(
+            (8, 4),
+            azam_decode_slice(b"xytxvyyfh5wgg1", &mut dst).unwrap()
+        );
+        assert_eq!([0xde, 0xad, 0xbe, 0xef], dst);
+        assert_eq!((3, 2), azam_decode_slice(b"hg0", &mut dst).unwrap());
+        assert_eq!([0x01, 0x00], dst[..2]);
+        assert_eq!(
+            AzamErrorKind::BufferTooSmall,
+            azam_decode_slice(b"xytxvyyf", &mut dst[..3])
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            AzamErrorKind::LeadingZero,
+            azam_decode_slice(b"gh0", &mut dst).unwrap_err().kind()
+        );
+        assert_eq!(
+            AzamErrorKind::Truncated,
+            azam_decode_slice(b"xy", &mut dst).unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn test_azam_decode_byte_string_slice() {
+        let mut dst = [0u8; 4];
+        assert_eq!(
+            (6, 2),
+            azam_decode_byte_string_slice(b"hgghn0wgg1", &mut dst).unwrap()
+        );
+        assert_eq!([0x00, 0x15], dst[..2]);
+        assert_eq!(
+            (2, 0),
+            azam_decode_byte_string_slice(b"h0", &mut dst).unwrap()
+        );
+        assert_eq!(
+            AzamErrorKind::BufferTooSmall,
+            azam_decode_byte_string_slice(b"hgghn0", &mut dst[..1])
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            AzamErrorKind::InvalidSection,
+            azam_decode_byte_string_slice(b"h5", &mut dst)
+                .unwrap_err()
+                .kind()
+        );
+    }
+
+    #[test]
+    fn test_section_len() {
+        assert_eq!(8, section_len(b"xytxvyyfh5").unwrap());
+        assert_eq!(1, section_len(b"0").unwrap());
+        assert_eq!(
+            AzamErrorKind::Truncated,
+            section_len(b"xy").unwrap_err().kind()
+        );
+        let err = section_len(b"gh0").unwrap_err();
+        assert_eq!(AzamErrorKind::LeadingZero, err.kind());
+        assert_eq!(Some(b'g'), err.byte());
+        assert_eq!(Some(0), nybble_value(b'0'));
+        assert_eq!(Some(0x1f), nybble_value(b'z'));
+        assert_eq!(None, nybble_value(b'_'));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_azam_decode_slice_to_vec() {
+        use std::vec;
+        let mut dst = vec![0x15u8];
+        assert_eq!(
+            (4, 2),
+            azam_decode_slice_to_vec("wgg1h5", &mut dst).unwrap()
+        );
+        assert_eq!(vec![0x15, 0xc0, 0x01], dst);
+        assert_eq!(
+            vec![vec![0xde, 0xad, 0xbe, 0xef], vec![0x15], vec![0xc0, 0x01]],
+            azam_decode_bytes_vec("xytxvyyfh5wgg1").unwrap()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_reader_read_section() {
+        let mut reader = AzamReader::new("xytxvyyfh5wgg1ph0".as_bytes());
+        let mut dst = Vec::<u8>::new();
+        assert_eq!(8, reader.read_section(&mut dst).unwrap());
+        assert_eq!(2, reader.read_section(&mut dst).unwrap());
+        assert_eq!(4, reader.read_section(&mut dst).unwrap());
+        assert_eq!(3, reader.read_section(&mut dst).unwrap());
+        assert_eq!(
+            vec![0xde, 0xad, 0xbe, 0xef, 0x15, 0xc0, 0x01, 0x06, 0x10],
+            dst
+        );
+        let err = reader.read_section(&mut dst).unwrap_err();
+        assert_eq!(AzamErrorKind::Truncated, err.kind());
+        assert_eq!(17, err.offset());
+        assert_eq!(4, err.section());
+
+        let mut dst = Vec::<u8>::new();
+        assert_eq!(
+            4,
+            azam_decode_read(&mut "wgg1h5".as_bytes(), &mut dst).unwrap()
+        );
+        assert_eq!(vec![0xc0, 0x01], dst);
+    }
+
+    #[cfg(feature = "std")]
     fn assert_decode_uints_err(
-        kind: ErrorKind,
+        kind: AzamErrorKind,
         u8_str: &str,
         u16_str: &str,
         u32_str: &str,
@@ -430,20 +1687,23 @@ mod tests {
         assert_eq!(kind, u128::azam_decode(u128_str).unwrap_err().kind());
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_decode_uints_empty() {
-        assert_decode_uints_err(ErrorKind::UnexpectedEof, "", "", "", "", "");
+        assert_decode_uints_err(AzamErrorKind::Truncated, "", "", "", "", "");
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_decode_uints_invalid_symbol() {
-        assert_decode_uints_err(ErrorKind::InvalidData, "_", "_", "_", "_", "_");
+        assert_decode_uints_err(AzamErrorKind::InvalidSymbol, "_", "_", "_", "_", "_");
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_decode_uints_invalid_middle_nybble() {
         assert_decode_uints_err(
-            ErrorKind::InvalidData,
+            AzamErrorKind::InvalidSymbol,
             "z_",
             "zz_f",
             "zzzzzz_f",
@@ -452,10 +1712,11 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_decode_uints_invalid_last_nybble() {
         assert_decode_uints_err(
-            ErrorKind::UnexpectedEof,
+            AzamErrorKind::Truncated,
             "hh",
             "hhhh",
             "hhhhhhhh",
@@ -464,10 +1725,11 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_decode_uints_invalid_last_nybble_odd() {
         assert_decode_uints_err(
-            ErrorKind::UnexpectedEof,
+            AzamErrorKind::Truncated,
             "h",
             "hhh",
             "hhhhhhh",
@@ -476,12 +1738,87 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_uints_overflow() {
+        assert_decode_uints_err(
+            AzamErrorKind::Overflow,
+            "hgg0",
+            "hgggg0",
+            "hgggggggg0",
+            "hgggggggggggggggg0",
+            "hgggggggggggggggggggggggggggggggg0",
+        );
+        let err = azam_decode!("h5hgg0", u8, u8).unwrap_err();
+        assert_eq!(AzamErrorKind::Overflow, err.kind());
+        assert_eq!(2, err.offset());
+        assert_eq!(1, err.section());
+        // Invalid symbol beyond the width of type is still reported as such
+        assert_eq!(
+            AzamErrorKind::InvalidSymbol,
+            u8::azam_decode("hg_0").unwrap_err().kind()
+        );
+        // Reader is left at the section boundary
+        let reader = &mut AzamReader::new("hgg0h5".as_bytes());
+        assert_eq!(
+            AzamErrorKind::Overflow,
+            u8::azam_decode_from(reader).unwrap_err().kind()
+        );
+        assert_eq!(0x15u8, u8::azam_decode_from(reader).unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_uints_lossy() {
+        assert_eq!(0xffu8, u8::azam_decode_saturating("hgg0").unwrap());
+        assert_eq!(0x00u8, u8::azam_decode_truncating("hgg0").unwrap());
+        assert_eq!(0x15u8, u8::azam_decode_saturating("h5").unwrap());
+        assert_eq!(0x15u8, u8::azam_decode_truncating("h5").unwrap());
+        assert_eq!(0xffffu16, u16::azam_decode_saturating("xytxvyyf").unwrap());
+        assert_eq!(0xbeefu16, u16::azam_decode_truncating("xytxvyyf").unwrap());
+        assert_eq!(
+            u128::MAX,
+            u128::azam_decode_saturating("hgggggggggggggggggggggggggggggggg0").unwrap()
+        );
+        assert_eq!(
+            AzamErrorKind::Truncated,
+            u32::azam_decode_truncating("xytxvyyzh").unwrap_err().kind()
+        );
+        let reader = &mut AzamReader::new("xytxvyyfh5".as_bytes());
+        assert_eq!(0xefu8, u8::azam_decode_truncating_from(reader).unwrap());
+        assert_eq!(0x15u8, u8::azam_decode_saturating_from(reader).unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_uints_roundtrip() {
+        use crate::encode::AzamEncode;
+        for shift in 0..128 {
+            let value = u128::MAX >> shift;
+            let encoded = value.azam_encode();
+            assert_eq!(value, u128::azam_decode(&encoded).unwrap());
+            assert_eq!(
+                value,
+                u128::azam_decode_read(&mut encoded.as_bytes()).unwrap()
+            );
+            let value = value as u32;
+            let encoded = value.azam_encode();
+            assert_eq!(value, u32::azam_decode(&encoded).unwrap());
+            assert_eq!(
+                value,
+                u32::azam_decode_read(&mut encoded.as_bytes()).unwrap()
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
     macro_rules! assert_azam_decode {
         ($e:expr, $t:ty, $v:expr) => {
             assert_eq!($e, <$t>::azam_decode($v).unwrap());
         };
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_decode_uints() {
         assert_azam_decode!(0x00u8, u8, "0");
@@ -537,4 +1874,456 @@ mod tests {
         assert_azam_decode!(0xffffffffffffffffu64, u64, "zzzzzzzzzzzzzzzf_");
         assert_azam_decode!(0xffffffffffffffffu64, u64, "zzzzzzzzzzzzzzzfaaaa");
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_ints() {
+        assert_azam_decode!(0i8, i8, "r0");
+        assert_azam_decode!(1i16, i16, "rh1");
+        assert_azam_decode!(16i32, i32, "rjh0");
+        assert_azam_decode!(-1i64, i64, "qzf");
+        assert_azam_decode!(-16i128, i128, "qz0");
+        assert_azam_decode!(-17isize, isize, "qyyf");
+        assert_azam_decode!(i8::MIN, i8, "qyr0");
+        assert_azam_decode!(i8::MAX, i8, "rjqf");
+        assert_azam_decode!(i128::MIN, i128, "pgrgggggggggggggggggggggggggggggg0");
+        assert_azam_decode!(i128::MAX, i128, "tgqzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf");
+        assert_eq!(
+            (-1i8, 0x15u8, 1i32),
+            azam_decode!("qzfh5rh1", i8, u8, i32).unwrap()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_ints_err() {
+        // Non-minimal or malformed sections
+        for value in ["0", "rh0", "rjg1", "qyzf", "qzzf", "rgh0", "rjhh0"] {
+            assert_eq!(
+                AzamErrorKind::InvalidSection,
+                i32::azam_decode(value).unwrap_err().kind()
+            );
+        }
+        for value in ["r", "rg", "rh", "rjh", "qyz"] {
+            assert_eq!(
+                AzamErrorKind::Truncated,
+                i32::azam_decode(value).unwrap_err().kind()
+            );
+        }
+        // Out of range values
+        assert_eq!(
+            AzamErrorKind::Overflow,
+            i8::azam_decode("rjt0").unwrap_err().kind()
+        );
+        assert_eq!(
+            AzamErrorKind::Overflow,
+            i8::azam_decode("qxgg0").unwrap_err().kind()
+        );
+        assert_eq!(
+            AzamErrorKind::Overflow,
+            i8::azam_decode("qxyg0").unwrap_err().kind()
+        );
+        // Reader is left at the section boundary
+        let reader = &mut AzamReader::new("rkhg0rjhhh0".as_bytes());
+        assert_eq!(
+            AzamErrorKind::Overflow,
+            i8::azam_decode_from(reader).unwrap_err().kind()
+        );
+        assert_eq!(
+            AzamErrorKind::InvalidSection,
+            i8::azam_decode_from(reader).unwrap_err().kind()
+        );
+        assert_eq!(11, reader.offset());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_ints_lossy() {
+        assert_eq!(i8::MAX, i8::azam_decode_saturating("rkhg0").unwrap());
+        assert_eq!(i8::MIN, i8::azam_decode_saturating("qxyg0").unwrap());
+        assert_eq!(0x00i8, i8::azam_decode_truncating("rkhg0").unwrap());
+        assert_eq!(0x00i8, i8::azam_decode_truncating("qxyg0").unwrap());
+        assert_eq!(-1i8, i8::azam_decode_truncating("rjzf").unwrap());
+        assert_eq!(-5i16, i16::azam_decode_saturating("qzb").unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_ints_roundtrip() {
+        use crate::encode::AzamEncode;
+        for shift in 0..128 {
+            for value in [i128::MAX >> shift, i128::MIN >> shift] {
+                let encoded = value.azam_encode();
+                assert_eq!(value, i128::azam_decode(&encoded).unwrap());
+                let value = value as i16;
+                let encoded = value.azam_encode();
+                assert_eq!(value, i16::azam_decode(&encoded).unwrap());
+            }
+        }
+        for value in i16::MIN..=i16::MAX {
+            assert_eq!(value, i16::azam_decode(&value.azam_encode()).unwrap());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_floats() {
+        assert_azam_decode!(0.0f32, f32, "rrrgggggg0");
+        assert_azam_decode!(1.0f64, f64, "sgvzzgggggggggggg0");
+        assert_azam_decode!(-1.0f64, f64, "sgmggzzzzzzzzzzzzf");
+        assert_eq!(
+            (-0.0f32).to_bits(),
+            f32::azam_decode("rrqzzzzzzf").unwrap().to_bits()
+        );
+        assert_eq!(
+            AzamErrorKind::InvalidSection,
+            f32::azam_decode("qzf").unwrap_err().kind()
+        );
+        assert_eq!(
+            AzamErrorKind::Overflow,
+            f32::azam_decode("rshggggggg0").unwrap_err().kind()
+        );
+        // Overlong sections must not wrap into a valid key
+        let overlong = format!("thz{}0", "g".repeat(31));
+        assert_eq!(
+            AzamErrorKind::Overflow,
+            f32::azam_decode(&overlong).unwrap_err().kind()
+        );
+        assert_eq!(
+            AzamErrorKind::Overflow,
+            f64::azam_decode(&overlong).unwrap_err().kind()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_floats_roundtrip() {
+        use crate::encode::AzamEncode;
+        let values = [
+            0.0,
+            -0.0,
+            1.5,
+            -1.5,
+            f64::MIN_POSITIVE,
+            f64::MIN_POSITIVE / 2.0,
+            f64::MAX,
+            f64::MIN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            -f64::NAN,
+            f64::from_bits(0x7ff0_0000_0000_0001),
+            f64::from_bits(0xfff8_dead_beef_0001),
+        ];
+        for value in values {
+            let decoded = f64::azam_decode(&value.azam_encode()).unwrap();
+            assert_eq!(value.to_bits(), decoded.to_bits());
+            let value = value as f32;
+            let decoded = f32::azam_decode(&value.azam_encode()).unwrap();
+            assert_eq!(value.to_bits(), decoded.to_bits());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_primitives() {
+        assert_azam_decode!(false, bool, "0");
+        assert_azam_decode!(true, bool, "1");
+        assert_azam_decode!('A', char, "rjm1");
+        assert_azam_decode!('\u{20ac}', char, "rmjgtc");
+        assert_azam_decode!(char::MAX, char, "rphgzzzf");
+        assert_azam_decode!(0xdeadbeefusize, usize, "xytxvyyf");
+        assert_azam_decode!(NonZeroU8::new(0x15).unwrap(), NonZeroU8, "h5");
+        assert_azam_decode!(NonZeroI32::new(-1).unwrap(), NonZeroI32, "qzf");
+        assert_azam_decode!(Wrapping(0xefu8), Wrapping<u8>, "xytxvyyf");
+        assert_azam_decode!(Wrapping(-1i8), Wrapping<i8>, "rjzf");
+        assert_eq!(
+            (true, 'A', false),
+            azam_decode!("1rjm10", bool, char, bool).unwrap()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_exact() {
+        assert_eq!(0xffu8, u8::azam_decode_exact("zf").unwrap());
+        assert_eq!(0xffu8, u8::azam_decode("zfaaaa").unwrap());
+        let err = u8::azam_decode_exact("zfaaaa").unwrap_err();
+        assert_eq!(AzamErrorKind::TrailingData, err.kind());
+        assert_eq!(2, err.offset());
+        assert_eq!(1, err.section());
+        assert_eq!(Some(b'a'), err.byte());
+        assert_eq!(Some("zfaaaa"), err.input());
+        let err = u32::azam_decode_exact("xytxvyyf_").unwrap_err();
+        assert_eq!(AzamErrorKind::TrailingData, err.kind());
+        assert_eq!(8, err.offset());
+        let err = u32::azam_decode_exact("xytx").unwrap_err();
+        assert_eq!(AzamErrorKind::Truncated, err.kind());
+        assert_eq!(
+            vec![1u8, 2],
+            Vec::<u8>::azam_decode_exact("hghgj0").unwrap()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_strict() {
+        assert_eq!(0xdeadbeefu32, u32::azam_decode_strict("xytxvyyf").unwrap());
+        assert_eq!(0xdeadbeefu32, u32::azam_decode("XYTXVYYF").unwrap());
+        let err = u32::azam_decode_strict("xytxVyyf").unwrap_err();
+        assert_eq!(AzamErrorKind::NonCanonical, err.kind());
+        assert_eq!(4, err.offset());
+        assert_eq!(Some(b'V'), err.byte());
+        let err = <(u8, u16)>::azam_decode_strict("h5wggl").unwrap_err();
+        assert_eq!(AzamErrorKind::NonCanonical, err.kind());
+        assert_eq!(5, err.offset());
+        assert_eq!(1, err.section());
+        let err = u8::azam_decode_strict("o").unwrap_err();
+        assert_eq!(AzamErrorKind::NonCanonical, err.kind());
+        let mut reader = AzamReader::new("h5wgg1".as_bytes()).strict();
+        assert_eq!(
+            (0x15u8, 0xc001u16),
+            <(u8, u16)>::azam_decode_from(&mut reader).unwrap()
+        );
+
+        // Overlong ordered sections of canonical symbols are not canonical encodings either
+        let overlong = format!("thh{}m1", "g".repeat(30));
+        assert_eq!('A', char::azam_decode_strict("rjm1").unwrap());
+        let err = char::azam_decode_strict(&overlong).unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSection, err.kind());
+        assert_eq!(0, err.offset());
+        let err = i32::azam_decode_strict(&overlong).unwrap_err();
+        assert_eq!(AzamErrorKind::Overflow, err.kind());
+        let err = i128::azam_decode_strict(&overlong).unwrap_err();
+        assert_eq!(AzamErrorKind::Overflow, err.kind());
+        let overlong = format!("thz{}0", "g".repeat(31));
+        let err = f32::azam_decode_strict(&overlong).unwrap_err();
+        assert_eq!(AzamErrorKind::Overflow, err.kind());
+        let err = f64::azam_decode_strict(&overlong).unwrap_err();
+        assert_eq!(AzamErrorKind::Overflow, err.kind());
+        let overlong = format!("thh{}1", "g".repeat(31));
+        let mut reader = DecodeOptions::STRICT.reader(overlong.as_bytes());
+        let err = crate::__private::decode_variant(&mut reader, 2).unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSection, err.kind());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_primitives_err() {
+        for value in ["2", "h0", "h1"] {
+            assert_eq!(
+                AzamErrorKind::InvalidSection,
+                bool::azam_decode(value).unwrap_err().kind()
+            );
+        }
+        // Surrogate, beyond char::MAX, negative and overlong 'A'
+        let overlong = format!("thh{}m1", "g".repeat(30));
+        for value in ["rmxrg0", "rphhggg0", "qzf", &overlong] {
+            assert_eq!(
+                AzamErrorKind::InvalidSection,
+                char::azam_decode(value).unwrap_err().kind()
+            );
+        }
+        assert_eq!(
+            AzamErrorKind::InvalidSection,
+            NonZeroU16::azam_decode("0").unwrap_err().kind()
+        );
+        assert_eq!(
+            AzamErrorKind::InvalidSection,
+            NonZeroI64::azam_decode("r0").unwrap_err().kind()
+        );
+        // Reader is left at the section boundary
+        let reader = &mut AzamReader::new("h5h0".as_bytes());
+        assert!(bool::azam_decode_from(reader).is_err());
+        assert_eq!(0x10u8, u8::azam_decode_from(reader).unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_byte_string() {
+        let mut dst = vec![0x15u8];
+        assert_eq!(
+            (8, 3),
+            azam_decode_byte_string_to_vec("hgggggh0h5", &mut dst).unwrap()
+        );
+        assert_eq!(vec![0x15, 0x00, 0x00, 0x01], dst);
+        let mut dst = [0u8; 3];
+        assert_eq!(
+            (8, 3),
+            azam_decode_byte_string_slice(b"hgggggh0h5", &mut dst).unwrap()
+        );
+        assert_eq!([0x00, 0x00, 0x01], dst);
+        assert_eq!(
+            AzamErrorKind::BufferTooSmall,
+            azam_decode_byte_string_slice(b"hgggggh0", &mut dst[..2])
+                .unwrap_err()
+                .kind()
+        );
+        assert_azam_decode!(Vec::<u8>::new(), Vec<u8>, "h0");
+        assert_azam_decode!(vec![0x00u8], Vec<u8>, "hgg0");
+        assert_azam_decode!([0xdeu8, 0xad, 0xbe, 0xef], [u8; 4], "hxytxvyyz0");
+        assert_azam_decode!(vec![0x15u8].into_boxed_slice(), Box<[u8]>, "hhn0");
+        assert_eq!(
+            (vec![], [0x00u8], 0x15u8),
+            azam_decode!("h0hgg0h5", Vec<u8>, [u8; 1], u8).unwrap()
+        );
+        assert_eq!(
+            vec![vec![0x10], vec![0x10, 0x00], vec![0x15]],
+            azam_decode_bytes_vec("h0hgg0h5").unwrap()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_byte_string_err() {
+        // Missing marker, odd nybble count, non-zero terminal nybble
+        for value in ["0", "j0", "hg0", "hgg1", "h1"] {
+            assert_eq!(
+                AzamErrorKind::InvalidSection,
+                Vec::<u8>::azam_decode(value).unwrap_err().kind()
+            );
+        }
+        assert_eq!(
+            AzamErrorKind::Truncated,
+            Vec::<u8>::azam_decode("hgg").unwrap_err().kind()
+        );
+        assert_eq!(
+            AzamErrorKind::InvalidSection,
+            <[u8; 2]>::azam_decode("hgg0").unwrap_err().kind()
+        );
+        let mut dst = vec![0x15u8];
+        assert!(azam_decode_byte_string_to_vec("hggg0", &mut dst).is_err());
+        assert!(azam_decode_byte_string_slice(b"hggg0", &mut [0u8; 4]).is_err());
+        assert_eq!(vec![0x15u8], dst);
+        // Reader is left at the section boundary
+        let reader = &mut AzamReader::new("jgg0h5".as_bytes());
+        assert!(Vec::<u8>::azam_decode_from(reader).is_err());
+        assert_eq!(0x15u8, u8::azam_decode_from(reader).unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_string() {
+        assert_azam_decode!("", String, "h0");
+        assert_azam_decode!("ab", String, "hphpj0");
+        assert_azam_decode!("\u{20ac}", String, "hyjrjtw0");
+        assert_azam_decode!(Box::<str>::from("a"), Box<str>, "hph0");
+        assert_eq!(
+            (String::from("a"), 0x15u8),
+            azam_decode!("hph0h5", String, u8).unwrap()
+        );
+        let err = String::azam_decode("hzz0").unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidUtf8, err.kind());
+        assert_eq!(
+            "invalid UTF-8 in text section at offset 0 in section 0\n  hzz0\n  ^",
+            err.to_string()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_option() {
+        assert_azam_decode!(None, Option<u32>, "0");
+        assert_azam_decode!(Some(0x15u8), Option<u8>, "1h5");
+        assert_azam_decode!(Some(None), Option<Option<u8>>, "10");
+        assert_eq!(
+            (None, 0x15u8, Some(String::from("a"))),
+            azam_decode!("0h51hph0", Option<i8>, u8, Option<String>).unwrap()
+        );
+        assert_eq!(
+            AzamErrorKind::InvalidSection,
+            Option::<u8>::azam_decode("2h5").unwrap_err().kind()
+        );
+        assert_eq!(
+            AzamErrorKind::Truncated,
+            Option::<u8>::azam_decode("1").unwrap_err().kind()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_tuple() {
+        assert_azam_decode!((), (), "");
+        assert_azam_decode!((0x15u8,), (u8,), "h5");
+        assert_azam_decode!(
+            (0xdeadbeefu32, 0x15u8, 0xc001u16),
+            (u32, u8, u16),
+            "xytxvyyfh5wgg1"
+        );
+        assert_azam_decode!(
+            ((0x15u8, -1i8), (String::from("a"), false)),
+            ((u8, i8), (String, bool)),
+            "h5qzfhph00"
+        );
+        assert_azam_decode!(
+            (0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8),
+            (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8),
+            "0123456789ab"
+        );
+        let err = <(u32, u8, u16)>::azam_decode("xytxvyyfh5w_g1").unwrap_err();
+        assert_eq!(11, err.offset());
+        assert_eq!(Some("xytxvyyfh5w_g1"), err.input());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_seq() {
+        assert_azam_decode!(Vec::<u32>::new(), Vec<u32>, "0");
+        assert_azam_decode!(vec![0x15u16, 0xc001], Vec<u16>, "1h51wgg10");
+        assert_azam_decode!([-1i8, 0], [i8; 2], "1qzf1r00");
+        assert_azam_decode!(
+            vec![String::from("a"), String::from("b")].into_boxed_slice(),
+            Box<[String]>,
+            "1hph01hpj00"
+        );
+        assert_azam_decode!(vec![vec![0x15u8], vec![]], Vec<Vec<u8>>, "1hhn01h00");
+        assert_eq!(
+            (vec![1i32], 0x15u8),
+            azam_decode!("1rh10h5", Vec<i32>, u8).unwrap()
+        );
+        assert_eq!(
+            AzamErrorKind::InvalidSection,
+            <[i8; 3]>::azam_decode("1qzf1r00").unwrap_err().kind()
+        );
+        assert_eq!(
+            AzamErrorKind::InvalidSection,
+            Vec::<i8>::azam_decode("2").unwrap_err().kind()
+        );
+        assert_eq!(
+            AzamErrorKind::Truncated,
+            Vec::<i8>::azam_decode("1qzf").unwrap_err().kind()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_reverse() {
+        use crate::encode::AzamEncode;
+        use std::cmp::Reverse;
+        for value in [0u32, 1, 0x15, 0xdeadbeef, u32::MAX] {
+            let encoded = Reverse(value).azam_encode();
+            assert_eq!(value, Reverse::<u32>::azam_decode(&encoded).unwrap().0);
+        }
+        let value = (String::from("a"), Some(-1i8));
+        let encoded = Reverse(&value).azam_encode();
+        assert_eq!(
+            value,
+            Reverse::<(String, Option<i8>)>::azam_decode(&encoded)
+                .unwrap()
+                .0
+        );
+        // Trailing symbols, invalid byte and empty inner value
+        for value in ["hhghg0", "hzz0", "h0"] {
+            assert_eq!(
+                AzamErrorKind::InvalidSection,
+                Reverse::<u8>::azam_decode(value).unwrap_err().kind()
+            );
+        }
+        assert_eq!(
+            AzamErrorKind::Overflow,
+            Reverse::<u8>::azam_decode(&Reverse(0x100u16).azam_encode())
+                .unwrap_err()
+                .kind()
+        );
+    }
 }
@@ -1,20 +1,259 @@
-use std::io::{ErrorKind, Read, Result, Write};
+#[cfg(feature = "std")]
+use crate::buf::AzamBuf;
+use crate::error::{AzamErrorKind, Result};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "std")]
+use core::fmt;
+#[cfg(feature = "std")]
+use std::cmp::Reverse;
+#[cfg(feature = "std")]
+use std::io::{Read, Write};
+#[cfg(feature = "std")]
+use std::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+};
 
-const LOWER_ALPHABETS: &[u8] = b"0123456789abcdef";
-const HIGHER_ALPHABETS: &[u8] = b"ghjkmnpqrstvwxyz";
+/// Derive macro of [`AzamEncode`] trait, available with `derive` feature.
+#[cfg(feature = "derive")]
+pub use azamcodec_derive::AzamEncode;
 
-enum LeadNybbleStatus {
-    None,
-    PreviousHigh,
-    PreviousLow,
+pub(crate) const LOWER_ALPHABETS: &[u8] = b"0123456789abcdef";
+pub(crate) const HIGHER_ALPHABETS: &[u8] = b"ghjkmnpqrstvwxyz";
+
+/// Returns the length of Azam codec encoded bytes for given byte array.
+///
+/// # Arguments
+///
+/// * `value` - Byte array
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::encode::azam_encoded_len;
+///
+/// let len = azam_encoded_len(&0xdeadbeefu32.to_be_bytes()); // 8
+/// ```
+pub fn azam_encoded_len(value: &[u8]) -> usize {
+    match value.iter().position(|byte| *byte != 0) {
+        Some(index) => (value.len() - index) * 2 - (value[index] >> 4 == 0) as usize,
+        None => 1,
+    }
+}
+
+/// Encode given byte array to `dst`, which must be exactly [`azam_encoded_len`] bytes long.
+fn encode_into(value: &[u8], dst: &mut [u8]) {
+    let index = match value.iter().position(|byte| *byte != 0) {
+        Some(index) => index,
+        None => {
+            // All bytes are 0x00, flush as single low nybble
+            dst[0] = LOWER_ALPHABETS[0];
+            return;
+        }
+    };
+    let mut pos = 0usize;
+    for byte in &value[index..] {
+        let high_nybble = byte >> 4;
+        // Skip leading high nybble of first byte if it is 0
+        if pos > 0 || high_nybble > 0 {
+            dst[pos] = HIGHER_ALPHABETS[high_nybble as usize];
+            pos += 1;
+        }
+        dst[pos] = HIGHER_ALPHABETS[(byte & 0x0fu8) as usize];
+        pos += 1;
+    }
+    // Last nybble is always a low nybble
+    dst[pos - 1] = LOWER_ALPHABETS[(value[value.len() - 1] & 0x0fu8) as usize];
 }
-/// Given a source of a [`Read`] instance of byte stream, encode to Azam codeca nd write to [`Write`] instance.
+
+/// Returns the length of Azam codec encoded bytes for given byte array, when encoded as a byte-string section.
+///
+/// # Arguments
+///
+/// * `value` - Byte array
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::encode::azam_byte_string_len;
+///
+/// let len = azam_byte_string_len(&[0x00, 0x15]); // 6
+/// ```
+pub fn azam_byte_string_len(value: &[u8]) -> usize {
+    value.len() * 2 + 2
+}
+
+/// Encode given byte array as a byte-string section to `dst`, which must be exactly [`azam_byte_string_len`] bytes long.
+/// Section starts with a high nybble 1 marker, followed by 2 high nybbles for each byte, and ends with a low nybble 0.
+fn encode_byte_string_into(value: &[u8], dst: &mut [u8]) {
+    dst[0] = HIGHER_ALPHABETS[1];
+    for (byte, symbols) in value.iter().zip(dst[1..].chunks_exact_mut(2)) {
+        symbols[0] = HIGHER_ALPHABETS[(byte >> 4) as usize];
+        symbols[1] = HIGHER_ALPHABETS[(byte & 0x0fu8) as usize];
+    }
+    dst[dst.len() - 1] = LOWER_ALPHABETS[0];
+}
+
+/// Given a byte array, encode to Azam codec byte-string section and write to `dst` byte slice.
+/// Unlike [`azam_encode_slice`], leading zero bytes are kept, so the exact length of byte array is preserved, including empty byte arrays.
+/// Byte-string sections sort in the same order as the byte arrays, with shorter prefixes first.
+/// Returns a tuple of number of bytes read from `value` and number of bytes written to `dst`.
+///
+/// # Arguments
+///
+/// * `value` - Byte array
+/// * `dst` - Byte slice to write Azam codec encoded bytes, must be at least [`azam_byte_string_len`] bytes long
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::encode::azam_encode_byte_string_slice;
+///
+/// let mut dst = [0u8; 16];
+/// let (read, written) = azam_encode_byte_string_slice(&[0x00, 0x15], &mut dst).unwrap(); // (2, 6)
+/// let encoded = std::str::from_utf8(&dst[..written]).unwrap(); // "hgghn0"
+/// ```
+pub fn azam_encode_byte_string_slice(value: &[u8], dst: &mut [u8]) -> Result<(usize, usize)> {
+    let len = azam_byte_string_len(value);
+    if dst.len() < len {
+        return Err(AzamErrorKind::BufferTooSmall.into());
+    }
+    encode_byte_string_into(value, &mut dst[..len]);
+    Ok((value.len(), len))
+}
+
+/// Given a byte array, encode to Azam codec byte-string section and append to `dst` byte vector.
+/// Returns a tuple of number of bytes read from `value` and number of bytes appended to `dst`.
+///
+/// # Arguments
+///
+/// * `value` - Byte array
+/// * `dst` - Byte vector to append Azam codec encoded bytes
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::encode::azam_encode_byte_string_to_vec;
+///
+/// let mut dst = Vec::<u8>::new();
+/// azam_encode_byte_string_to_vec([0x00, 0x15], &mut dst).unwrap(); // (2, 6)
+/// let encoded = String::from_utf8(dst).unwrap(); // "hgghn0"
+/// ```
+#[cfg(feature = "alloc")]
+pub fn azam_encode_byte_string_to_vec<T: AsRef<[u8]>>(
+    value: T,
+    dst: &mut Vec<u8>,
+) -> Result<(usize, usize)> {
+    let value = value.as_ref();
+    let len = azam_byte_string_len(value);
+    let start = dst.len();
+    dst.resize(start + len, 0);
+    encode_byte_string_into(value, &mut dst[start..]);
+    Ok((value.len(), len))
+}
+
+/// Given a byte array, encode to Azam codec and write to `dst` byte slice.
+/// Returns a tuple of number of bytes read from `value` and number of bytes written to `dst`.
+/// Empty byte array cannot be encoded and fails with [`AzamErrorKind::Truncated`] error, since `"0"` is the encoding of `[0x00]`, see [`azam_encode_byte_string_slice`] to encode it.
+///
+/// # Arguments
+///
+/// * `value` - Byte array
+/// * `dst` - Byte slice to write Azam codec encoded bytes, must be at least [`azam_encoded_len`] bytes long
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::encode::azam_encode_slice;
+///
+/// let mut dst = [0u8; 16];
+/// let (read, written) = azam_encode_slice(&0xdeadbeefu32.to_be_bytes(), &mut dst).unwrap(); // (4, 8)
+/// let encoded = std::str::from_utf8(&dst[..written]).unwrap(); // "xytxvyyf"
+/// ```
+pub fn azam_encode_slice(value: &[u8], dst: &mut [u8]) -> Result<(usize, usize)> {
+    if value.is_empty() {
+        return Err(AzamErrorKind::Truncated.into());
+    }
+    let len = azam_encoded_len(value);
+    if dst.len() < len {
+        return Err(AzamErrorKind::BufferTooSmall.into());
+    }
+    encode_into(value, &mut dst[..len]);
+    Ok((value.len(), len))
+}
+
+/// Given a byte array, encode to Azam codec and append to `dst` byte vector.
+/// Returns a tuple of number of bytes read from `value` and number of bytes appended to `dst`.
+/// Empty byte array fails with [`AzamErrorKind::Truncated`] error, see [`azam_encode_slice`].
+///
+/// # Arguments
+///
+/// * `value` - Byte array
+/// * `dst` - Byte vector to append Azam codec encoded bytes
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::encode::azam_encode_slice_to_vec;
+///
+/// let mut dst = Vec::<u8>::new();
+/// azam_encode_slice_to_vec(0xdeadbeefu32.to_be_bytes(), &mut dst).unwrap(); // (4, 8)
+/// let encoded = String::from_utf8(dst).unwrap(); // "xytxvyyf"
+/// ```
+#[cfg(feature = "alloc")]
+pub fn azam_encode_slice_to_vec<T: AsRef<[u8]>>(
+    value: T,
+    dst: &mut Vec<u8>,
+) -> Result<(usize, usize)> {
+    let value = value.as_ref();
+    if value.is_empty() {
+        return Err(AzamErrorKind::Truncated.into());
+    }
+    let len = azam_encoded_len(value);
+    let start = dst.len();
+    dst.resize(start + len, 0);
+    encode_into(value, &mut dst[start..]);
+    Ok((value.len(), len))
+}
+
+/// Given multiple byte arrays, encode each as a section of multi-section Azam codec and append to `dst` byte vector.
+/// Returns a tuple of number of bytes read from `values` and number of bytes appended to `dst`.
+/// Empty byte arrays fail with [`AzamErrorKind::Truncated`] error, see [`azam_encode_slice`].
+///
+/// # Arguments
+///
+/// * `values` - Byte arrays
+/// * `dst` - Byte vector to append Azam codec encoded bytes
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::encode::azam_encode_slices_to_vec;
+///
+/// let mut dst = Vec::<u8>::new();
+/// let src: [&[u8]; 3] = [&0xdeadbeefu32.to_be_bytes(), &[0x15u8], &0xc001u16.to_be_bytes()];
+/// azam_encode_slices_to_vec(src, &mut dst).unwrap(); // (7, 14)
+/// let encoded = String::from_utf8(dst).unwrap(); // "xytxvyyfh5wgg1"
+/// ```
+#[cfg(feature = "alloc")]
+pub fn azam_encode_slices_to_vec<I>(values: I, dst: &mut Vec<u8>) -> Result<(usize, usize)>
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    let mut read = 0usize;
+    let mut written = 0usize;
+    for value in values {
+        let (r, w) = azam_encode_slice_to_vec(value, dst)?;
+        read += r;
+        written += w;
+    }
+    Ok((read, written))
+}
+
+/// Given a source of a [`Read`] instance of byte stream, encode to Azam codec and write to [`Write`] instance.
+/// Empty stream fails with [`AzamErrorKind::Truncated`] error, see [`azam_encode_slice`].
 ///
 /// # Arguments
 ///
 /// * `reader` - Byte stream
 /// * `writer` - Stream to write Azam codec encoded bytes
-/// * `limit` - Maximum number of bytes to read
 ///
 /// # Examples
 /// ```rust
@@ -23,105 +262,58 @@ enum LeadNybbleStatus {
 /// let mut src = 0xdeadbeefu32.to_be_bytes();
 /// let mut dst = Vec::<u8>::new();
 /// azam_encode_write(&mut src.as_slice(), &mut dst).unwrap();
-/// let encoded = String::from_utf8(dst).unwrap(); // "xytxvyyfh5wgg1"
+/// let encoded = String::from_utf8(dst).unwrap(); // "xytxvyyf"
 /// ```
+#[cfg(feature = "std")]
 pub fn azam_encode_write<R: Read, W: Write>(reader: &mut R, writer: &mut W) -> Result<usize> {
-    let mut byte = [0u8; 1];
-    let mut count: usize = 0;
-
-    // Read first byte
-    reader.read_exact(&mut byte)?;
-    count += 1;
-
-    // Update lead nybble status
-    let mut lead_nybble = if (byte[0] >> 4) > 0 {
-        LeadNybbleStatus::PreviousHigh
-    } else if (byte[0] & 0x0fu8) > 0 {
-        LeadNybbleStatus::PreviousLow
-    } else {
-        LeadNybbleStatus::None
-    };
-    let mut prev_byte = byte[0];
-
+    let mut chunk = [0u8; 32];
+    let mut symbols = [0u8; 64];
+    let mut count = 0usize;
+    // Last byte is held back until end of stream, since its low nybble terminates the section
+    let mut last: Option<u8> = None;
+    // Leading zero nybbles are skipped until the first non-zero nybble is written
+    let mut started = false;
     loop {
-        // Read and consume some of read error
-        match reader.read_exact(&mut byte) {
-            Ok(()) => {
-                count += 1;
-                match lead_nybble {
-                    LeadNybbleStatus::None => {
-                        // Not flushing previous byte
-                        // Update lead nybble status
-                        lead_nybble = if (byte[0] >> 4) > 0 {
-                            LeadNybbleStatus::PreviousHigh
-                        } else if (byte[0] & 0x0fu8) > 0 {
-                            LeadNybbleStatus::PreviousLow
-                        } else {
-                            LeadNybbleStatus::None
-                        };
-                    }
-                    LeadNybbleStatus::PreviousHigh => {
-                        // Flush previous byte as highs
-                        let high_nybble = prev_byte >> 4;
-                        let low_nybble = prev_byte & 0x0fu8;
-                        writer.write_all(&[HIGHER_ALPHABETS[high_nybble as usize]])?;
-                        writer.write_all(&[HIGHER_ALPHABETS[low_nybble as usize]])?;
-                    }
-                    LeadNybbleStatus::PreviousLow => {
-                        // Only flush previous byte's low nybble as high
-                        let low_nybble = prev_byte & 0x0fu8;
-                        writer.write_all(&[HIGHER_ALPHABETS[low_nybble as usize]])?;
-                        lead_nybble = LeadNybbleStatus::PreviousHigh;
+        let read = match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err.into()),
+        };
+        count += read;
+        let mut len = 0usize;
+        for byte in &chunk[..read] {
+            if let Some(prev) = last.replace(*byte) {
+                for nybble in [prev >> 4, prev & 0x0fu8] {
+                    if started || nybble > 0 {
+                        symbols[len] = HIGHER_ALPHABETS[nybble as usize];
+                        len += 1;
+                        started = true;
                     }
                 }
-                prev_byte = byte[0];
-            }
-            Err(err) => {
-                // Consume EOF error, because we expect it
-                if err.kind() == ErrorKind::UnexpectedEof {
-                    // Flushing of previous byte done after exit loop
-                    break;
-                } else {
-                    // Rethrow error
-                    return Err(err);
-                }
             }
         }
+        writer.write_all(&symbols[..len])?;
     }
-
-    // Flush last byte
-    match lead_nybble {
-        LeadNybbleStatus::None => {
-            if count > 0 {
-                // Previous byte is 0x00
-                // Flush as low nybble
-                writer.write_all(&[LOWER_ALPHABETS[0]])?;
-            }
-        }
-        LeadNybbleStatus::PreviousHigh => {
-            // Flush previous byte's high nybble as high
-            // Flush previous byte's low nybble as low
-            let high_nybble = prev_byte >> 4;
-            let low_nybble = prev_byte & 0x0fu8;
-            writer.write_all(&[HIGHER_ALPHABETS[high_nybble as usize]])?;
-            writer.write_all(&[LOWER_ALPHABETS[low_nybble as usize]])?;
-        }
-        LeadNybbleStatus::PreviousLow => {
-            // Flush previous byte's low nybble as low
-            let low_nybble = prev_byte & 0x0fu8;
-            writer.write_all(&[LOWER_ALPHABETS[low_nybble as usize]])?;
-        }
+    let last = last.ok_or(AzamErrorKind::Truncated)?;
+    let high_nybble = last >> 4;
+    if started || high_nybble > 0 {
+        writer.write_all(&[HIGHER_ALPHABETS[high_nybble as usize]])?;
     }
-
+    writer.write_all(&[LOWER_ALPHABETS[(last & 0x0fu8) as usize]])?;
     Ok(count)
 }
 
-/// Given a source of a [`Read`] instance of byte stream, encode to Azam codec and return as byte array.
+/// Given a byte array, encode to Azam codec and return as byte array.
 ///
 /// # Arguments
 ///
 /// * `value` - Byte array
 ///
+/// # Panics
+///
+/// Panics if `value` is empty, see [`azam_encode_slice`].
+///
 /// # Examples
 /// ```rust
 /// use azamcodec::encode::azam_encode_bytes_to_bytes;
@@ -130,9 +322,10 @@ pub fn azam_encode_write<R: Read, W: Write>(reader: &mut R, writer: &mut W) -> R
 /// let dst = azam_encode_bytes_to_bytes(src);
 /// let encoded = String::from_utf8(dst).unwrap(); // "xytxvyyf"
 /// ```
+#[cfg(feature = "alloc")]
 pub fn azam_encode_bytes_to_bytes(value: Vec<u8>) -> Vec<u8> {
     let mut encoded = Vec::<u8>::new();
-    azam_encode_write(&mut value.as_slice(), &mut encoded).unwrap();
+    azam_encode_slice_to_vec(value, &mut encoded).unwrap();
     encoded
 }
 
@@ -142,6 +335,10 @@ pub fn azam_encode_bytes_to_bytes(value: Vec<u8>) -> Vec<u8> {
 ///
 /// * `value` - Byte array
 ///
+/// # Panics
+///
+/// Panics if `value` is empty, see [`azam_encode_slice`].
+///
 /// # Examples
 /// ```rust
 /// use azamcodec::encode::azam_encode_bytes;
@@ -149,6 +346,7 @@ pub fn azam_encode_bytes_to_bytes(value: Vec<u8>) -> Vec<u8> {
 /// let mut src = 0xdeadbeefu32.to_be_bytes().to_vec();
 /// let encoded = azam_encode_bytes(src); // "xytxvyyf"
 /// ```
+#[cfg(feature = "alloc")]
 pub fn azam_encode_bytes(value: Vec<u8>) -> String {
     String::from_utf8(azam_encode_bytes_to_bytes(value)).unwrap()
 }
@@ -159,6 +357,10 @@ pub fn azam_encode_bytes(value: Vec<u8>) -> String {
 ///
 /// * `value` - Byte array
 ///
+/// # Panics
+///
+/// Panics if any byte array of `value` is empty, see [`azam_encode_slice`].
+///
 /// # Examples
 /// ```rust
 /// use azamcodec::encode::azam_encode_bytes_vec_to_bytes;
@@ -170,11 +372,10 @@ pub fn azam_encode_bytes(value: Vec<u8>) -> String {
 /// let dst = azam_encode_bytes_vec_to_bytes(src);
 /// let encoded = String::from_utf8(dst); // "xytxvyyfh5wgg1"
 /// ```
+#[cfg(feature = "alloc")]
 pub fn azam_encode_bytes_vec_to_bytes(value: Vec<Vec<u8>>) -> Vec<u8> {
     let mut encoded = Vec::<u8>::new();
-    for vec in value {
-        encoded.append(&mut azam_encode_bytes_to_bytes(vec.clone()));
-    }
+    azam_encode_slices_to_vec(value, &mut encoded).unwrap();
     encoded
 }
 
@@ -184,6 +385,10 @@ pub fn azam_encode_bytes_vec_to_bytes(value: Vec<Vec<u8>>) -> Vec<u8> {
 ///
 /// * `value` - Byte array
 ///
+/// # Panics
+///
+/// Panics if any byte array of `value` is empty, see [`azam_encode_slice`].
+///
 /// # Examples
 /// ```rust
 /// use azamcodec::encode::azam_encode_bytes_vec;
@@ -194,13 +399,16 @@ pub fn azam_encode_bytes_vec_to_bytes(value: Vec<Vec<u8>>) -> Vec<u8> {
 /// src.push(0xc001u16.to_be_bytes().to_vec());
 /// let encoded = azam_encode_bytes_vec(src); // "xytxvyyfh5wgg1"
 /// ```
+#[cfg(feature = "alloc")]
 pub fn azam_encode_bytes_vec(value: Vec<Vec<u8>>) -> String {
     String::from_utf8(azam_encode_bytes_vec_to_bytes(value)).unwrap()
 }
 
 /// Trait to extend types to support encoding to Azam codec.
+#[cfg(feature = "std")]
 pub trait AzamEncode {
     /// Given a destination of a [`Write`] instance, generate byte array as needed, and write Azam codec encoded byte stream.
+    /// Returns the number of bytes written to `writer`.
     ///
     /// # Arguments
     ///
@@ -209,31 +417,650 @@ pub trait AzamEncode {
 
     /// Generate byte array as needed and return Azam codec encoded string.
     fn azam_encode(&self) -> String;
+
+    /// Write Azam codec encoded string to a [`fmt::Write`] instance, e.g. a [`fmt::Formatter`], without allocating an intermediate string.
+    /// See [`Azam`](crate::azam::Azam) to format values with `{}`.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination of Azam codec encoded string
+    ///
+    /// # Examples
+    /// ```rust
+    /// use azamcodec::encode::AzamEncode;
+    ///
+    /// let mut x = String::from("id=");
+    /// (0xdeadbeefu32, 0x15u8, 0xc001u16).azam_encode_fmt(&mut x).unwrap(); // "id=xytxvyyfh5wgg1"
+    /// ```
+    fn azam_encode_fmt<F: fmt::Write + ?Sized>(&self, writer: &mut F) -> fmt::Result {
+        self.azam_encode_write(&mut FmtWriter(writer))
+            .map(|_| ())
+            .map_err(|_| fmt::Error)
+    }
+
+    /// Encode a sequence of values, which is used by implementations of slices, arrays and vectors.
+    /// Each value is preceded by a single low nybble 1 section, and the sequence ends with a single low nybble 0 section.
+    /// Types may override this to use a more compact encoding, e.g. [`u8`] uses a byte-string section.
+    #[doc(hidden)]
+    fn azam_encode_seq_write<W: Write>(values: &[Self], writer: &mut W) -> Result<usize>
+    where
+        Self: Sized,
+    {
+        let mut len = 0usize;
+        for value in values {
+            len += true.azam_encode_write(writer)?;
+            len += value.azam_encode_write(writer)?;
+        }
+        Ok(len + false.azam_encode_write(writer)?)
+    }
+
+    /// Encode to a new fixed capacity [`AzamBuf`] without allocation.
+    /// Returns [`AzamErrorKind::BufferTooSmall`] error if encoded value does not fit, see [`max_encoded_len`] to size the buffer.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use azamcodec::{buf::AzamBuf, encode::{max_encoded_len, AzamEncode}};
+    ///
+    /// let x: AzamBuf<{ max_encoded_len::<(u32, u8, u16)>() }> =
+    ///     (0xdeadbeefu32, 0x15u8, 0xc001u16).azam_encode_to_buf().unwrap(); // "xytxvyyfh5wgg1"
+    /// ```
+    fn azam_encode_to_buf<const N: usize>(&self) -> Result<AzamBuf<N>> {
+        let mut buf = AzamBuf::new();
+        self.azam_encode_into_buf(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Append encoded value to given [`AzamBuf`] without allocation, and return the number of bytes appended.
+    /// Returns [`AzamErrorKind::BufferTooSmall`] error if encoded value does not fit, in which case the buffer is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - Buffer to append Azam codec encoded string
+    fn azam_encode_into_buf<const N: usize>(&self, buf: &mut AzamBuf<N>) -> Result<usize> {
+        let len = buf.len();
+        // Multiple sections may be written before running out of capacity
+        self.azam_encode_write(buf)
+            .inspect_err(|_| buf.truncate(len))
+    }
+}
+
+/// Adapter of [`fmt::Write`] to [`Write`], so encoders can stream symbols into a formatter.
+/// Azam codec encoded bytes are always ASCII, so any chunk of them is a valid string.
+#[cfg(feature = "std")]
+struct FmtWriter<'a, F: fmt::Write + ?Sized>(&'a mut F);
+
+#[cfg(feature = "std")]
+impl<F: fmt::Write + ?Sized> Write for FmtWriter<'_, F> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let value = std::str::from_utf8(buf)
+            .map_err(|_| std::io::Error::from(std::io::ErrorKind::InvalidData))?;
+        self.0
+            .write_str(value)
+            .map_err(|_| std::io::Error::other(fmt::Error))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Types with a bounded encoded length, which is known at compile time.
+/// Used to size [`AzamBuf`] for allocation-free encoding, see [`max_encoded_len`].
+///
+/// Slices, vectors and strings have unbounded encoded length, so they do not implement this trait.
+#[cfg(feature = "std")]
+pub trait AzamEncodedLen: AzamEncode {
+    /// Maximum number of bytes of encoded value.
+    const MAX_ENCODED_LEN: usize;
+}
+
+/// Returns the maximum number of bytes of encoded value of given type, e.g. 32 for [`u128`].
+/// This is a const fn, so it can be used as capacity of [`AzamBuf`].
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::{buf::AzamBuf, encode::{max_encoded_len, AzamEncode}};
+///
+/// assert_eq!(32, max_encoded_len::<u128>());
+/// let x: AzamBuf<{ max_encoded_len::<u64>() }> = u64::MAX.azam_encode_to_buf().unwrap(); // "zzzzzzzzzzzzzzzf"
+/// ```
+#[cfg(feature = "std")]
+pub const fn max_encoded_len<T: AzamEncodedLen + ?Sized>() -> usize {
+    T::MAX_ENCODED_LEN
 }
 
 macro_rules! azam_encode_uint_impl {
-    ($t:ty) => {
+    ($t:ty $({ $($extra:tt)* })?) => {
+        #[cfg(feature = "std")]
         impl AzamEncode for $t {
             fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
-                $crate::encode::azam_encode_write(&mut self.to_be_bytes().as_ref(), writer)
+                let mut buf = [0u8; <$t>::BITS as usize / 4];
+                let start = azam_encode_uint_buf!(*self, $t, buf);
+                writer.write_all(&buf[start..])?;
+                Ok(buf.len() - start)
             }
 
+            #[inline]
             fn azam_encode(&self) -> String {
-                let mut bytes = Vec::<u8>::new();
-                $crate::encode::AzamEncode::azam_encode_write(self, &mut bytes).unwrap();
-                String::from_utf8(bytes).unwrap()
+                let mut buf = [0u8; <$t>::BITS as usize / 4];
+                let start = azam_encode_uint_buf!(*self, $t, buf);
+                std::str::from_utf8(&buf[start..]).unwrap().to_owned()
             }
+
+            $($($extra)*)?
         }
     };
 }
 
-azam_encode_uint_impl!(u8);
+/// Encode uint value to the tail of a stack buffer of `BITS / 4` bytes, and return the start index of encoded bytes.
+/// Only significant nybbles are written, computed from leading zeros, with a minimum of one nybble.
+#[cfg(feature = "std")]
+macro_rules! azam_encode_uint_buf {
+    ($value:expr, $t:ty, $buf:ident) => {{
+        const NYBBLES: usize = <$t>::BITS as usize / 4;
+        let value: $t = $value;
+        let count = ((<$t>::BITS - value.leading_zeros()).div_ceil(4) as usize).max(1);
+        let start = NYBBLES - count;
+        // Last nybble is always a low nybble, the rest are high nybbles
+        $buf[NYBBLES - 1] = LOWER_ALPHABETS[(value & 0x0f) as usize];
+        let mut rest = value;
+        for byte in $buf[start..NYBBLES - 1].iter_mut().rev() {
+            rest >>= 4;
+            *byte = HIGHER_ALPHABETS[(rest & 0x0f) as usize];
+        }
+        start
+    }};
+}
+
+azam_encode_uint_impl!(u8 {
+    /// Encode as a byte-string section, see [`azam_encode_byte_string_slice`].
+    fn azam_encode_seq_write<W: Write>(values: &[Self], writer: &mut W) -> Result<usize> {
+        let mut encoded = Vec::<u8>::with_capacity(azam_byte_string_len(values));
+        let (_, len) = azam_encode_byte_string_to_vec(values, &mut encoded)?;
+        writer.write_all(&encoded)?;
+        Ok(len)
+    }
+});
 azam_encode_uint_impl!(u16);
 azam_encode_uint_impl!(u32);
 azam_encode_uint_impl!(u64);
 azam_encode_uint_impl!(u128);
+azam_encode_uint_impl!(usize);
+
+/// Maximum number of symbols of an ordered section, which is a header of 2 nybbles and up to 32 nybbles of payload.
+#[cfg(feature = "std")]
+const ORDERED_MAX_LEN: usize = 34;
+
+/// Ordered section encoded to a stack buffer, which encoded strings sort in the same order as the values.
+/// Header byte is 0x80 plus the payload nybble count for non-negative values, or 0x80 minus the payload nybble count for negative values.
+/// Payload is the low nybbles of two's complement value, so larger magnitudes get longer sections without breaking the order.
+#[cfg(feature = "std")]
+pub(crate) struct OrderedSection {
+    buf: [u8; ORDERED_MAX_LEN],
+    start: usize,
+}
+
+#[cfg(feature = "std")]
+impl OrderedSection {
+    /// Encode non-negative value, with only significant nybbles as payload.
+    pub(crate) fn unsigned(value: u128) -> Self {
+        let len = (u128::BITS - value.leading_zeros()).div_ceil(4) as usize;
+        Self::new(false, value, len)
+    }
+
+    /// Encode signed value, with only significant nybbles as payload.
+    /// Negative values keep at least one nybble, so -1 sorts after every other negative value.
+    pub(crate) fn signed(value: i128) -> Self {
+        if value >= 0 {
+            return Self::unsigned(value as u128);
+        }
+        let len = ((i128::BITS - (!value).leading_zeros()).div_ceil(4) as usize).max(1);
+        Self::new(true, value as u128, len)
+    }
+
+    fn new(negative: bool, payload: u128, len: usize) -> Self {
+        let header = if negative {
+            0x80u8 - len as u8
+        } else {
+            0x80u8 + len as u8
+        };
+        let mut buf = [0u8; ORDERED_MAX_LEN];
+        let start = ORDERED_MAX_LEN - len - 2;
+        buf[start] = HIGHER_ALPHABETS[(header >> 4) as usize];
+        buf[start + 1] = HIGHER_ALPHABETS[(header & 0x0f) as usize];
+        let mut rest = payload;
+        for byte in buf[start + 2..].iter_mut().rev() {
+            *byte = HIGHER_ALPHABETS[(rest & 0x0f) as usize];
+            rest >>= 4;
+        }
+        // Last nybble is always a low nybble, which is the header itself for zero
+        let last = match len {
+            0 => header & 0x0f,
+            _ => (payload & 0x0f) as u8,
+        };
+        buf[ORDERED_MAX_LEN - 1] = LOWER_ALPHABETS[last as usize];
+        Self { buf, start }
+    }
+
+    /// Returns the encoded symbols.
+    pub(crate) fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.buf[self.start..]).unwrap()
+    }
+
+    /// Write the encoded symbols to writer, and return the number of bytes written.
+    pub(crate) fn write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        writer.write_all(&self.buf[self.start..])?;
+        Ok(ORDERED_MAX_LEN - self.start)
+    }
+}
+
+macro_rules! azam_encode_int_impl {
+    ($t:ty) => {
+        #[cfg(feature = "std")]
+        impl AzamEncode for $t {
+            fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+                OrderedSection::signed(*self as i128).write(writer)
+            }
+
+            fn azam_encode(&self) -> String {
+                OrderedSection::signed(*self as i128).as_str().to_owned()
+            }
+        }
+    };
+}
+
+azam_encode_int_impl!(i8);
+azam_encode_int_impl!(i16);
+azam_encode_int_impl!(i32);
+azam_encode_int_impl!(i64);
+azam_encode_int_impl!(i128);
+azam_encode_int_impl!(isize);
+
+macro_rules! azam_encode_float_impl {
+    ($t:ty, $u:ty) => {
+        #[cfg(feature = "std")]
+        impl AzamEncode for $t {
+            fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+                OrderedSection::unsigned(float_key!(*self, $u) as u128).write(writer)
+            }
+
+            fn azam_encode(&self) -> String {
+                OrderedSection::unsigned(float_key!(*self, $u) as u128)
+                    .as_str()
+                    .to_owned()
+            }
+        }
+    };
+}
+
+/// Convert float bits to an unsigned key, which sorts in the same order as `total_cmp`.
+/// Negative values have all bits flipped, and the rest have only the sign bit flipped.
+#[cfg(feature = "std")]
+macro_rules! float_key {
+    ($value:expr, $u:ty) => {{
+        let bits: $u = $value.to_bits();
+        let sign: $u = !(<$u>::MAX >> 1);
+        match bits & sign {
+            0 => bits ^ sign,
+            _ => !bits,
+        }
+    }};
+}
+
+azam_encode_float_impl!(f32, u32);
+azam_encode_float_impl!(f64, u64);
+
+#[cfg(feature = "std")]
+impl AzamEncode for bool {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        writer.write_all(match self {
+            false => b"0",
+            true => b"1",
+        })?;
+        Ok(1)
+    }
+
+    /// Encode as a single low nybble, `"0"` for false and `"1"` for true.
+    fn azam_encode(&self) -> String {
+        match self {
+            false => "0".to_owned(),
+            true => "1".to_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl AzamEncode for char {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        OrderedSection::unsigned(*self as u128).write(writer)
+    }
+
+    /// Encode Unicode scalar value as an ordered section, so encoded strings sort in code point order.
+    fn azam_encode(&self) -> String {
+        OrderedSection::unsigned(*self as u128).as_str().to_owned()
+    }
+}
+
+macro_rules! azam_encode_non_zero_impl {
+    ($t:ty) => {
+        #[cfg(feature = "std")]
+        impl AzamEncode for $t {
+            fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+                self.get().azam_encode_write(writer)
+            }
+
+            fn azam_encode(&self) -> String {
+                self.get().azam_encode()
+            }
+        }
+    };
+}
+
+azam_encode_non_zero_impl!(NonZeroU8);
+azam_encode_non_zero_impl!(NonZeroU16);
+azam_encode_non_zero_impl!(NonZeroU32);
+azam_encode_non_zero_impl!(NonZeroU64);
+azam_encode_non_zero_impl!(NonZeroU128);
+azam_encode_non_zero_impl!(NonZeroUsize);
+azam_encode_non_zero_impl!(NonZeroI8);
+azam_encode_non_zero_impl!(NonZeroI16);
+azam_encode_non_zero_impl!(NonZeroI32);
+azam_encode_non_zero_impl!(NonZeroI64);
+azam_encode_non_zero_impl!(NonZeroI128);
+azam_encode_non_zero_impl!(NonZeroIsize);
+
+/// Encode each value preceded by a single low nybble 1 section, and end with a single low nybble 0 section.
+/// Encoded sequences sort element-wise by encoded elements, with shorter prefixes first.
+/// This is the order of the sequences only if the encoding of element type is order-preserving, e.g. signed integers, floats and strings.
+/// Unsigned integers are not, so `vec![0xffu32]` (`"1zf0"`) sorts after `vec![0x100u32]` (`"1hg00"`).
+/// Sequences of [`u8`] are encoded as a byte-string section instead, see [`azam_encode_byte_string_slice`].
+#[cfg(feature = "std")]
+impl<T: AzamEncode> AzamEncode for [T] {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        T::azam_encode_seq_write(self, writer)
+    }
+
+    fn azam_encode(&self) -> String {
+        let mut encoded = Vec::<u8>::new();
+        self.azam_encode_write(&mut encoded).unwrap();
+        String::from_utf8(encoded).unwrap()
+    }
+}
+
+/// Encode the same as slices, see [`AzamEncode`] implementation of `[T]`.
+#[cfg(feature = "std")]
+impl<T: AzamEncode, const N: usize> AzamEncode for [T; N] {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        self.as_slice().azam_encode_write(writer)
+    }
+
+    fn azam_encode(&self) -> String {
+        self.as_slice().azam_encode()
+    }
+}
+
+/// Encode the same as slices, see [`AzamEncode`] implementation of `[T]`.
+#[cfg(feature = "std")]
+impl<T: AzamEncode> AzamEncode for Vec<T> {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        self.as_slice().azam_encode_write(writer)
+    }
+
+    fn azam_encode(&self) -> String {
+        self.as_slice().azam_encode()
+    }
+}
+
+/// Encode the same as slices, see [`AzamEncode`] implementation of `[T]`.
+#[cfg(feature = "std")]
+impl<T: AzamEncode> AzamEncode for Box<[T]> {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        self.as_ref().azam_encode_write(writer)
+    }
+
+    fn azam_encode(&self) -> String {
+        self.as_ref().azam_encode()
+    }
+}
+
+/// Encode UTF-8 bytes as a byte-string section, so encoded strings sort in the same order as the strings.
+#[cfg(feature = "std")]
+impl AzamEncode for str {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        self.as_bytes().azam_encode_write(writer)
+    }
+
+    fn azam_encode(&self) -> String {
+        self.as_bytes().azam_encode()
+    }
+}
+
+/// Encode UTF-8 bytes as a byte-string section, so encoded strings sort in the same order as the strings.
+#[cfg(feature = "std")]
+impl AzamEncode for String {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        self.as_bytes().azam_encode_write(writer)
+    }
+
+    fn azam_encode(&self) -> String {
+        self.as_bytes().azam_encode()
+    }
+}
+
+/// Encode [`None`] as a single low nybble 0 section, and [`Some`] as a single low nybble 1 section followed by the value.
+/// [`None`] sorts before all [`Some`] values, see [`NoneLast`](crate::option::NoneLast) to sort it after.
+#[cfg(feature = "std")]
+impl<T: AzamEncode> AzamEncode for Option<T> {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        match self {
+            None => false.azam_encode_write(writer),
+            Some(value) => Ok(true.azam_encode_write(writer)? + value.azam_encode_write(writer)?),
+        }
+    }
+
+    fn azam_encode(&self) -> String {
+        let mut encoded = Vec::<u8>::new();
+        self.azam_encode_write(&mut encoded).unwrap();
+        String::from_utf8(encoded).unwrap()
+    }
+}
+
+/// Encode nothing, which is useful as a placeholder in generic code.
+#[cfg(feature = "std")]
+impl AzamEncode for () {
+    fn azam_encode_write<W: Write>(&self, _writer: &mut W) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn azam_encode(&self) -> String {
+        String::new()
+    }
+}
+
+/// Encode nothing, like `()`, so marker fields of derived types need no attribute.
+#[cfg(feature = "std")]
+impl<T: ?Sized> AzamEncode for PhantomData<T> {
+    fn azam_encode_write<W: Write>(&self, _writer: &mut W) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn azam_encode(&self) -> String {
+        String::new()
+    }
+}
+
+/// Encode each element of tuple as consecutive sections.
+macro_rules! azam_encode_tuple_impl {
+    ($($name:ident)+) => {
+        #[cfg(feature = "std")]
+        impl<$($name: AzamEncode),+> AzamEncode for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+                let ($($name,)+) = self;
+                Ok(0 $(+ $name.azam_encode_write(writer)?)+)
+            }
+
+            fn azam_encode(&self) -> String {
+                let mut encoded = Vec::<u8>::new();
+                self.azam_encode_write(&mut encoded).unwrap();
+                String::from_utf8(encoded).unwrap()
+            }
+        }
+    };
+}
+
+azam_encode_tuple_impl!(A);
+azam_encode_tuple_impl!(A B);
+azam_encode_tuple_impl!(A B C);
+azam_encode_tuple_impl!(A B C D);
+azam_encode_tuple_impl!(A B C D E);
+azam_encode_tuple_impl!(A B C D E F);
+azam_encode_tuple_impl!(A B C D E F G);
+azam_encode_tuple_impl!(A B C D E F G H);
+azam_encode_tuple_impl!(A B C D E F G H I);
+azam_encode_tuple_impl!(A B C D E F G H I J);
+azam_encode_tuple_impl!(A B C D E F G H I J K);
+azam_encode_tuple_impl!(A B C D E F G H I J K L);
+
+#[cfg(feature = "std")]
+impl<T: AzamEncode + ?Sized> AzamEncode for &T {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        (**self).azam_encode_write(writer)
+    }
+
+    fn azam_encode(&self) -> String {
+        (**self).azam_encode()
+    }
+}
+
+/// Encode as a byte-string section of the symbols of inner value, each complemented to `31 - i` where `i` is the index of symbol in `0-9a-fg-z`.
+/// Encoded strings sort in the reverse order of inner value, which is useful for descending sort order in composite keys.
+#[cfg(feature = "std")]
+impl<T: AzamEncode> AzamEncode for Reverse<T> {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        let mut symbols = Vec::<u8>::new();
+        self.0.azam_encode_write(&mut symbols)?;
+        for symbol in symbols.iter_mut() {
+            *symbol = 31u8 - crate::decode::nybble_value(*symbol).unwrap_or_default();
+        }
+        symbols.as_slice().azam_encode_write(writer)
+    }
+
+    fn azam_encode(&self) -> String {
+        let mut encoded = Vec::<u8>::new();
+        self.azam_encode_write(&mut encoded).unwrap();
+        String::from_utf8(encoded).unwrap()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: AzamEncode> AzamEncode for Wrapping<T> {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        self.0.azam_encode_write(writer)
+    }
+
+    fn azam_encode(&self) -> String {
+        self.0.azam_encode()
+    }
+}
+
+macro_rules! azam_encoded_len_impl {
+    ($($t:ty => $len:expr,)+) => {
+        $(
+            #[cfg(feature = "std")]
+            impl AzamEncodedLen for $t {
+                const MAX_ENCODED_LEN: usize = $len;
+            }
+        )+
+    };
+}
+
+azam_encoded_len_impl! {
+    // Uint sections have up to one symbol per nybble
+    u8 => u8::BITS as usize / 4,
+    u16 => u16::BITS as usize / 4,
+    u32 => u32::BITS as usize / 4,
+    u64 => u64::BITS as usize / 4,
+    u128 => u128::BITS as usize / 4,
+    usize => usize::BITS as usize / 4,
+    // Ordered sections have a header of 2 symbols, followed by up to one symbol per nybble
+    i8 => 2 + i8::BITS as usize / 4,
+    i16 => 2 + i16::BITS as usize / 4,
+    i32 => 2 + i32::BITS as usize / 4,
+    i64 => 2 + i64::BITS as usize / 4,
+    i128 => 2 + i128::BITS as usize / 4,
+    isize => 2 + isize::BITS as usize / 4,
+    f32 => 2 + u32::BITS as usize / 4,
+    f64 => 2 + u64::BITS as usize / 4,
+    // char::MAX is 0x10ffff, which has 6 nybbles
+    char => 8,
+    bool => 1,
+    () => 0,
+    NonZeroU8 => u8::MAX_ENCODED_LEN,
+    NonZeroU16 => u16::MAX_ENCODED_LEN,
+    NonZeroU32 => u32::MAX_ENCODED_LEN,
+    NonZeroU64 => u64::MAX_ENCODED_LEN,
+    NonZeroU128 => u128::MAX_ENCODED_LEN,
+    NonZeroUsize => usize::MAX_ENCODED_LEN,
+    NonZeroI8 => i8::MAX_ENCODED_LEN,
+    NonZeroI16 => i16::MAX_ENCODED_LEN,
+    NonZeroI32 => i32::MAX_ENCODED_LEN,
+    NonZeroI64 => i64::MAX_ENCODED_LEN,
+    NonZeroI128 => i128::MAX_ENCODED_LEN,
+    NonZeroIsize => isize::MAX_ENCODED_LEN,
+}
+
+macro_rules! azam_encoded_len_tuple_impl {
+    ($($name:ident)+) => {
+        #[cfg(feature = "std")]
+        impl<$($name: AzamEncodedLen),+> AzamEncodedLen for ($($name,)+) {
+            const MAX_ENCODED_LEN: usize = 0 $(+ $name::MAX_ENCODED_LEN)+;
+        }
+    };
+}
+
+azam_encoded_len_tuple_impl!(A);
+azam_encoded_len_tuple_impl!(A B);
+azam_encoded_len_tuple_impl!(A B C);
+azam_encoded_len_tuple_impl!(A B C D);
+azam_encoded_len_tuple_impl!(A B C D E);
+azam_encoded_len_tuple_impl!(A B C D E F);
+azam_encoded_len_tuple_impl!(A B C D E F G);
+azam_encoded_len_tuple_impl!(A B C D E F G H);
+azam_encoded_len_tuple_impl!(A B C D E F G H I);
+azam_encoded_len_tuple_impl!(A B C D E F G H I J);
+azam_encoded_len_tuple_impl!(A B C D E F G H I J K);
+azam_encoded_len_tuple_impl!(A B C D E F G H I J K L);
+
+#[cfg(feature = "std")]
+impl<T: AzamEncodedLen> AzamEncodedLen for Option<T> {
+    const MAX_ENCODED_LEN: usize = 1 + T::MAX_ENCODED_LEN;
+}
+
+/// Byte-string section has a leading and a trailing symbol, and 2 symbols per symbol of inner value.
+#[cfg(feature = "std")]
+impl<T: AzamEncodedLen> AzamEncodedLen for Reverse<T> {
+    const MAX_ENCODED_LEN: usize = 2 + 2 * T::MAX_ENCODED_LEN;
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> AzamEncodedLen for PhantomData<T> {
+    const MAX_ENCODED_LEN: usize = 0;
+}
+
+#[cfg(feature = "std")]
+impl<T: AzamEncodedLen> AzamEncodedLen for Wrapping<T> {
+    const MAX_ENCODED_LEN: usize = T::MAX_ENCODED_LEN;
+}
+
+#[cfg(feature = "std")]
+impl<T: AzamEncodedLen + ?Sized> AzamEncodedLen for &T {
+    const MAX_ENCODED_LEN: usize = T::MAX_ENCODED_LEN;
+}
 
 /// Macro to encode tuples of any types that implements the [`AzamEncode`] trait to Azam codec encoded string.
+/// Up to 12 values are encoded as a tuple of references to the values, and more values are encoded one by one.
 ///
 /// # Examples
 ///
@@ -245,18 +1072,26 @@ azam_encode_uint_impl!(u128);
 /// // 0xc001u16 encodes to "wgg1".
 /// let x = azam_encode!(0xdeadbeefu32, 0x15u8, 0xc001u16); // "xytxvyyfh5wgg1"
 /// ```
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! azam_encode {
     () => {};
     ($value:expr) => {{
         $crate::encode::AzamEncode::azam_encode(&$value)
     }};
-    ($($values:expr),*) => {{
-        let mut bytes = Vec::<u8>::new();
+    // Tuples implement AzamEncode up to 12 elements, so longer lists are encoded value by value
+    ($v0:expr, $v1:expr, $v2:expr, $v3:expr, $v4:expr, $v5:expr, $v6:expr, $v7:expr, $v8:expr, $v9:expr, $v10:expr, $v11:expr, $v12:expr $(, $values:expr)*) => {{
+        let mut bytes = ::std::vec::Vec::<u8>::new();
+        $crate::azam_encode!(@each bytes; $v0, $v1, $v2, $v3, $v4, $v5, $v6, $v7, $v8, $v9, $v10, $v11, $v12 $(, $values)*);
+        ::std::string::String::from_utf8(bytes).unwrap()
+    }};
+    (@each $bytes:ident; $($values:expr),+) => {
         $(
-            $crate::encode::AzamEncode::azam_encode_write(&$values, &mut bytes).unwrap();
-        )*
-        String::from_utf8(bytes).unwrap()
+            $crate::encode::AzamEncode::azam_encode_write(&$values, &mut $bytes).unwrap();
+        )+
+    };
+    ($($values:expr),*) => {{
+        $crate::encode::AzamEncode::azam_encode(&($(&$values),*))
     }};
 }
 
@@ -264,6 +1099,7 @@ macro_rules! azam_encode {
 mod tests {
     use crate::encode::*;
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_azam_encode_example() {
         assert_eq!("xytxvyyf", 0xdeadbeefu32.azam_encode());
@@ -271,6 +1107,7 @@ mod tests {
         assert_eq!("wgg1", 0xc001u16.azam_encode());
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_azam_encode_uints() {
         assert_eq!("0", 0x00u8.azam_encode());
@@ -287,6 +1124,382 @@ mod tests {
         assert_eq!("hgg0", 0x1000u32.azam_encode());
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_ints() {
+        assert_eq!("r0", 0i8.azam_encode());
+        assert_eq!("rh1", 1i16.azam_encode());
+        assert_eq!("rhf", 15i32.azam_encode());
+        assert_eq!("rjh0", 16i64.azam_encode());
+        assert_eq!("qzf", (-1i8).azam_encode());
+        assert_eq!("qz0", (-16i16).azam_encode());
+        assert_eq!("qyyf", (-17i32).azam_encode());
+        assert_eq!("rjqf", i8::MAX.azam_encode());
+        assert_eq!("qyr0", i8::MIN.azam_encode());
+        assert_eq!(
+            "pgrgggggggggggggggggggggggggggggg0",
+            i128::MIN.azam_encode()
+        );
+        assert_eq!(
+            "tgqzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf",
+            i128::MAX.azam_encode()
+        );
+        assert_eq!("qzfrh1", azam_encode!(-1i64, 1isize));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_ints_order() {
+        let values = [
+            i64::MIN,
+            -0x1_0000_0000,
+            -0x1000,
+            -257,
+            -256,
+            -255,
+            -17,
+            -16,
+            -2,
+            -1,
+            0,
+            1,
+            15,
+            16,
+            255,
+            256,
+            0x1000,
+            i64::MAX,
+        ];
+        for pair in values.windows(2) {
+            assert!(pair[0].azam_encode() < pair[1].azam_encode());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_floats() {
+        assert_eq!("rrrgggggg0", 0.0f32.azam_encode());
+        assert_eq!("rrqzzzzzzf", (-0.0f32).azam_encode());
+        assert_eq!("sgvzzgggggggggggg0", 1.0f64.azam_encode());
+        assert_eq!("sgmggzzzzzzzzzzzzf", (-1.0f64).azam_encode());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_floats_order() {
+        let values = [
+            -f64::NAN,
+            f64::NEG_INFINITY,
+            f64::MIN,
+            -1.0,
+            -f64::MIN_POSITIVE,
+            -0.0,
+            0.0,
+            f64::MIN_POSITIVE,
+            1.0,
+            f64::MAX,
+            f64::INFINITY,
+            f64::NAN,
+        ];
+        for pair in values.windows(2) {
+            assert!(pair[0].total_cmp(&pair[1]).is_lt());
+            assert!(pair[0].azam_encode() < pair[1].azam_encode());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_primitives() {
+        assert_eq!("0", false.azam_encode());
+        assert_eq!("1", true.azam_encode());
+        assert_eq!("rjm1", 'A'.azam_encode());
+        assert_eq!("rmjgtc", '\u{20ac}'.azam_encode());
+        assert_eq!("rphgzzzf", char::MAX.azam_encode());
+        assert_eq!(0xdeadbeefu64.azam_encode(), 0xdeadbeefusize.azam_encode());
+        assert_eq!("h5", NonZeroU8::new(0x15).unwrap().azam_encode());
+        assert_eq!("qzf", NonZeroI32::new(-1).unwrap().azam_encode());
+        assert_eq!("xytxvyyf", Wrapping(0xdeadbeefu32).azam_encode());
+        assert_eq!("01rjm1", azam_encode!(false, true, 'A'));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_chars_order() {
+        let values = [
+            '\0',
+            '0',
+            'A',
+            'a',
+            '\u{7f}',
+            '\u{80}',
+            '\u{20ac}',
+            '\u{ffff}',
+            char::MAX,
+        ];
+        for pair in values.windows(2) {
+            assert!(pair[0].azam_encode() < pair[1].azam_encode());
+        }
+    }
+
+    #[test]
+    fn test_azam_encode_slice() {
+        let mut dst = [0u8; 8];
+        assert_eq!(
+            (4, 8),
+            azam_encode_slice(&0xdeadbeefu32.to_be_bytes(), &mut dst).unwrap()
+        );
+        assert_eq!(b"xytxvyyf", &dst);
+        assert_eq!((2, 1), azam_encode_slice(&[0x00, 0x00], &mut dst).unwrap());
+        assert_eq!(b"0", &dst[..1]);
+        assert_eq!((2, 3), azam_encode_slice(&[0x01, 0x00], &mut dst).unwrap());
+        assert_eq!(b"hg0", &dst[..3]);
+        assert_eq!(
+            AzamErrorKind::BufferTooSmall,
+            azam_encode_slice(&0xdeadbeefu32.to_be_bytes(), &mut dst[..7])
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            AzamErrorKind::Truncated,
+            azam_encode_slice(&[], &mut dst).unwrap_err().kind()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_write_stream() {
+        let mut dst = Vec::<u8>::new();
+        assert_eq!(
+            4,
+            azam_encode_write(&mut 0xdeadbeefu32.to_be_bytes().as_slice(), &mut dst).unwrap()
+        );
+        assert_eq!(b"xytxvyyf", dst.as_slice());
+        // Input spans multiple chunks, with leading zeros across a chunk boundary
+        let mut src = vec![0x00u8; 40];
+        src.extend_from_slice(&[0x01, 0x00, 0xab]);
+        src.extend_from_slice(&[0xffu8; 70]);
+        let mut dst = Vec::<u8>::new();
+        assert_eq!(
+            src.len(),
+            azam_encode_write(&mut src.as_slice(), &mut dst).unwrap()
+        );
+        assert_eq!(azam_encode_bytes(src), String::from_utf8(dst).unwrap());
+        let mut dst = Vec::<u8>::new();
+        azam_encode_write(&mut [0x00u8; 33].as_slice(), &mut dst).unwrap();
+        assert_eq!(b"0", dst.as_slice());
+    }
+
+    #[test]
+    fn test_azam_encoded_len() {
+        assert_eq!(8, azam_encoded_len(&0xdeadbeefu32.to_be_bytes()));
+        assert_eq!(3, azam_encoded_len(&[0x00, 0x01, 0x00]));
+        assert_eq!(1, azam_encoded_len(&[0x00, 0x00]));
+        assert_eq!(2, azam_byte_string_len(&[]));
+        assert_eq!(6, azam_byte_string_len(&[0x00, 0x15]));
+    }
+
+    #[test]
+    fn test_azam_encode_byte_string_slice() {
+        let mut dst = [0u8; 8];
+        assert_eq!(
+            (3, 8),
+            azam_encode_byte_string_slice(&[0x00, 0x00, 0x01], &mut dst).unwrap()
+        );
+        assert_eq!(b"hgggggh0", &dst);
+        assert_eq!(
+            AzamErrorKind::BufferTooSmall,
+            azam_encode_byte_string_slice(&[0x00, 0x00, 0x01], &mut dst[..7])
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            (0, 2),
+            azam_encode_byte_string_slice(&[], &mut dst).unwrap()
+        );
+        assert_eq!(b"h0", &dst[..2]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_azam_encode_bytes() {
+        assert_eq!(
+            "xytxvyyf",
+            azam_encode_bytes(0xdeadbeefu32.to_be_bytes().to_vec())
+        );
+        let src = std::vec![std::vec![0x15u8], std::vec![0x00, 0x00]];
+        assert_eq!("h50", azam_encode_bytes_vec(src));
+        let mut dst = Vec::<u8>::new();
+        assert_eq!(
+            (2, 6),
+            azam_encode_byte_string_to_vec([0x00u8, 0x15], &mut dst).unwrap()
+        );
+        assert_eq!(b"hgghn0", dst.as_slice());
+        let mut dst = Vec::<u8>::new();
+        let err = azam_encode_slice_to_vec([0u8; 0], &mut dst).unwrap_err();
+        assert_eq!(AzamErrorKind::Truncated, err.kind());
+        assert!(dst.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_byte_string() {
+        assert_eq!("h0", Vec::<u8>::new().azam_encode());
+        assert_eq!("hgg0", vec![0x00u8].azam_encode());
+        assert_eq!("hxytxvyyz0", [0xdeu8, 0xad, 0xbe, 0xef].azam_encode());
+        assert_eq!("hhn0", vec![0x15u8].into_boxed_slice().azam_encode());
+        assert_eq!("h0hgg0", azam_encode!([0u8; 0], [0u8; 1]));
+        let mut dst = Vec::<u8>::new();
+        let err = azam_encode_write(&mut [0u8; 0].as_slice(), &mut dst).unwrap_err();
+        assert_eq!(AzamErrorKind::Truncated, err.kind());
+        assert!(dst.is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic]
+    fn test_azam_encode_bytes_empty() {
+        azam_encode_bytes(Vec::new());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_byte_string_order() {
+        let values: [&[u8]; 7] = [
+            &[],
+            &[0x00],
+            &[0x00, 0x00],
+            &[0x00, 0x01],
+            &[0x01],
+            &[0x01, 0x00],
+            &[0xff],
+        ];
+        for pair in values.windows(2) {
+            assert!(pair[0].azam_encode() < pair[1].azam_encode());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_str() {
+        assert_eq!("h0", "".azam_encode());
+        assert_eq!("hph0", "a".azam_encode());
+        assert_eq!("hphpj0", String::from("ab").azam_encode());
+        assert_eq!("hyjrjtw0", "\u{20ac}".azam_encode());
+        assert_eq!("hph0h5", azam_encode!("a", 0x15u8));
+        let values = ["", "a", "ab", "b", "\u{7f}", "\u{20ac}", "\u{10ffff}"];
+        for pair in values.windows(2) {
+            assert!(pair[0].azam_encode() < pair[1].azam_encode());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_option() {
+        assert_eq!("0", None::<u32>.azam_encode());
+        assert_eq!("1h5", Some(0x15u8).azam_encode());
+        assert_eq!("10", Some(None::<u8>).azam_encode());
+        assert_eq!("0h51hph0", azam_encode!(None::<i8>, 0x15u8, Some("a")));
+        let values = [None, Some(i32::MIN), Some(-1), Some(0), Some(i32::MAX)];
+        for pair in values.windows(2) {
+            assert!(pair[0].azam_encode() < pair[1].azam_encode());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_tuple() {
+        assert_eq!("", ().azam_encode());
+        assert_eq!("h5", (0x15u8,).azam_encode());
+        assert_eq!(
+            "xytxvyyfh5wgg1",
+            (0xdeadbeefu32, 0x15u8, 0xc001u16).azam_encode()
+        );
+        assert_eq!("h5qzfhph00", ((0x15u8, -1i8), ("a", false)).azam_encode());
+        assert_eq!(
+            "0123456789ab",
+            (0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8).azam_encode()
+        );
+        let mut dst = Vec::<u8>::new();
+        assert_eq!(4, (0x15u8, 0x15u8).azam_encode_write(&mut dst).unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_seq() {
+        assert_eq!("0", Vec::<u32>::new().azam_encode());
+        assert_eq!("1h51wgg10", vec![0x15u16, 0xc001].azam_encode());
+        assert_eq!("1qzf1r00", [-1i8, 0].azam_encode());
+        assert_eq!("1hph01hpj00", ["a", "b"].as_slice().azam_encode());
+        assert_eq!("1hhn01h00", vec![vec![0x15u8], vec![]].azam_encode());
+        assert_eq!("1rh10h5", azam_encode!(&[1i32][..], 0x15u8));
+        let values: [&[i32]; 6] = [&[], &[-1], &[-1, 0], &[0], &[0, -1], &[1]];
+        for pair in values.windows(2) {
+            assert!(pair[0].azam_encode() < pair[1].azam_encode());
+        }
+        // Unsigned integers sort by their encoded strings, which is not their numeric order
+        assert_eq!("1zf0", vec![0xffu32].azam_encode());
+        assert_eq!("1hg00", vec![0x100u32].azam_encode());
+        assert!(vec![0xffu32].azam_encode() > vec![0x100u32].azam_encode());
+        assert!(vec![0x100u32, 0].azam_encode() < vec![0xffu32].azam_encode());
+        assert!(vec![0x1u32].azam_encode() < vec![0x1u32, 0].azam_encode());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_reverse() {
+        assert_eq!("hhz0", Reverse(0u8).azam_encode());
+        assert_eq!("hgyht0", Reverse(0x15u8).azam_encode());
+        let values = [
+            i32::MIN,
+            -0x100,
+            -1,
+            0,
+            1,
+            0x0f,
+            0x10,
+            0xff,
+            0x100,
+            i32::MAX,
+        ];
+        for pair in values.windows(2) {
+            assert!(Reverse(pair[0]).azam_encode() > Reverse(pair[1]).azam_encode());
+        }
+        let values = ["", "a", "ab", "b"];
+        for pair in values.windows(2) {
+            assert!(Reverse(pair[0]).azam_encode() > Reverse(pair[1]).azam_encode());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_azam_encode_slice_to_vec() {
+        let mut dst = b"h5".to_vec();
+        assert_eq!(
+            (2, 4),
+            azam_encode_slice_to_vec(0xc001u16.to_be_bytes(), &mut dst).unwrap()
+        );
+        assert_eq!(b"h5wgg1", dst.as_slice());
+
+        let mut dst = Vec::<u8>::new();
+        let src: [&[u8]; 3] = [&[0xde, 0xad, 0xbe, 0xef], &[0x15], &[0xc0, 0x01]];
+        assert_eq!((7, 14), azam_encode_slices_to_vec(src, &mut dst).unwrap());
+        assert_eq!(b"xytxvyyfh5wgg1", dst.as_slice());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_encode_fmt() {
+        let mut encoded = String::new();
+        0xdeadbeefu32.azam_encode_fmt(&mut encoded).unwrap();
+        (0x15u8, Some(-1i8), "a")
+            .azam_encode_fmt(&mut encoded)
+            .unwrap();
+        assert_eq!("xytxvyyfh51qzfhph0", encoded);
+        let encoded = format!("{}", crate::azam::Azam(&(0xdeadbeefu32, 0x15u8, 0xc001u16)));
+        assert_eq!("xytxvyyfh5wgg1", encoded);
+    }
+
+    #[cfg(feature = "std")]
     #[test]
     fn test_azam_encode_macro() {
         assert_eq!("zf", azam_encode!(0xffu8));
@@ -299,5 +1512,21 @@ mod tests {
             "zfzzzfzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzf",
             azam_encode!(0xffu8, 0xffffu16, 0xffffffffffffffffffffffffffffffffu128)
         );
+        assert_eq!(
+            "123456789abcdefh0h1",
+            azam_encode!(1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8)
+                + &azam_encode!(13u8, 14u8, 15u8, 16u8, 17u8)
+        );
+        assert_eq!(
+            "123456789abcdefh0h1",
+            azam_encode!(
+                1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8, 15u8,
+                16u8, 17u8
+            )
+        );
+        assert_eq!(
+            "123456789abcd",
+            azam_encode!(1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8)
+        );
     }
 }
@@ -0,0 +1,417 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::ToString};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+/// Result type of Azam codec operations, with [`AzamError`] as error type.
+pub type Result<T> = core::result::Result<T, AzamError>;
+
+/// Maximum number of input bytes kept on each side of the error offset, so errors of large inputs stay small.
+#[cfg(feature = "alloc")]
+const INPUT_CONTEXT: usize = 32;
+
+/// Kind of [`AzamError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AzamErrorKind {
+    /// Byte is not a valid Azam codec symbol.
+    InvalidSymbol,
+    /// Section starts with a high nybble 0 (`g` or `G`), which is forbidden.
+    LeadingZero,
+    /// Input ended before the last nybble of a section.
+    Truncated,
+    /// Section value does not fit into the target type.
+    Overflow,
+    /// Section is well-formed Azam codec, but not a valid encoding of the target type.
+    InvalidSection,
+    /// Text section is not valid UTF-8.
+    InvalidUtf8,
+    /// Symbol is valid, but not canonical, e.g. an alias or uppercase letter rejected by strict decoding.
+    NonCanonical,
+    /// Input exceeds a limit of [`DecodeOptions`](crate::options::DecodeOptions), e.g. maximum section length.
+    LimitExceeded,
+    /// Input has unconsumed data after the decoded sections.
+    TrailingData,
+    /// Destination buffer is too small for the output.
+    BufferTooSmall,
+    /// Underlying I/O error.
+    Io,
+    /// Value or target type has a shape that Azam codec cannot represent, e.g. a map.
+    Unsupported,
+    /// Error raised by a caller of the codec, e.g. serde implementation of a type.
+    Custom,
+}
+
+/// Error of Azam codec operations.
+///
+/// Decoding errors carry the byte offset and section index where the error occurred, and the offending byte if any.
+/// When decoding from a string, the input around the offset is kept so [`Display`](fmt::Display) can point at the offending byte.
+///
+/// # Examples
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use azamcodec::{decode::AzamDecode, error::AzamErrorKind};
+///
+/// let err = u32::azam_decode("xy_txvyyf").unwrap_err();
+/// assert_eq!(AzamErrorKind::InvalidSymbol, err.kind());
+/// assert_eq!(2, err.offset());
+/// assert_eq!(Some(b'_'), err.byte());
+/// println!("{}", err);
+/// // invalid symbol '_' at offset 2 in section 0
+/// //   xy_txvyyf
+/// //     ^
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AzamError {
+    kind: AzamErrorKind,
+    offset: usize,
+    section: usize,
+    byte: Option<u8>,
+    #[cfg(feature = "alloc")]
+    input: Option<Box<str>>,
+    #[cfg(feature = "alloc")]
+    input_start: usize,
+    #[cfg(feature = "alloc")]
+    input_len: usize,
+    #[cfg(feature = "alloc")]
+    message: Option<Box<str>>,
+    #[cfg(feature = "std")]
+    source: Option<io::Error>,
+}
+
+impl AzamError {
+    /// Create a new error of given kind, at offset 0 of section 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Kind of error
+    pub fn new(kind: AzamErrorKind) -> Self {
+        Self {
+            kind,
+            offset: 0,
+            section: 0,
+            byte: None,
+            #[cfg(feature = "alloc")]
+            input: None,
+            #[cfg(feature = "alloc")]
+            input_start: 0,
+            #[cfg(feature = "alloc")]
+            input_len: 0,
+            #[cfg(feature = "alloc")]
+            message: None,
+            #[cfg(feature = "std")]
+            source: None,
+        }
+    }
+
+    /// Returns the kind of this error.
+    pub fn kind(&self) -> AzamErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset in the input where this error occurred.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the index of the section where this error occurred.
+    pub fn section(&self) -> usize {
+        self.section
+    }
+
+    /// Returns the offending byte, if any.
+    pub fn byte(&self) -> Option<u8> {
+        self.byte
+    }
+
+    /// Returns the message of this error, if any.
+    #[cfg(feature = "alloc")]
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Returns the input string this error occurred in, if known.
+    /// Only up to 32 bytes on each side of [`AzamError::offset`] are kept, see [`AzamError::input_start`].
+    #[cfg(feature = "alloc")]
+    pub fn input(&self) -> Option<&str> {
+        self.input.as_deref()
+    }
+
+    /// Returns the byte offset in the whole input where [`AzamError::input`] starts, which is 0 unless the input is cut.
+    #[cfg(feature = "alloc")]
+    pub fn input_start(&self) -> usize {
+        self.input_start
+    }
+
+    /// Set the position of this error.
+    pub(crate) fn at(mut self, offset: usize, section: usize) -> Self {
+        self.offset = offset;
+        self.section = section;
+        self
+    }
+
+    /// Set the offending byte of this error.
+    pub(crate) fn with_byte(mut self, byte: u8) -> Self {
+        self.byte = Some(byte);
+        self
+    }
+
+    /// Set the message of this error, which is displayed for [`AzamErrorKind::Unsupported`] and [`AzamErrorKind::Custom`] errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Description of the error
+    #[cfg(feature = "alloc")]
+    pub fn with_message(mut self, message: impl fmt::Display) -> Self {
+        self.message = Some(message.to_string().into());
+        self
+    }
+
+    /// Set the input string this error occurred in, unless it is already set, so [`Display`](fmt::Display) can point at the offending byte.
+    /// Only up to 32 bytes on each side of the offset are kept, so errors of large untrusted inputs do not copy them.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Azam codec encoded string being decoded
+    #[cfg(feature = "alloc")]
+    pub fn with_input(mut self, input: &str) -> Self {
+        if self.input.is_none() && self.kind != AzamErrorKind::Io {
+            let mut start = self.offset.saturating_sub(INPUT_CONTEXT).min(input.len());
+            while !input.is_char_boundary(start) {
+                start -= 1;
+            }
+            let mut end = self.offset.saturating_add(INPUT_CONTEXT).min(input.len());
+            while !input.is_char_boundary(end) {
+                end += 1;
+            }
+            self.input = Some(input[start..end].into());
+            self.input_start = start;
+            self.input_len = input.len();
+        }
+        self
+    }
+}
+
+impl fmt::Display for AzamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let byte = self.byte.unwrap_or_default();
+        match self.kind {
+            AzamErrorKind::InvalidSymbol => write!(f, "invalid symbol {}", Symbol(byte))?,
+            AzamErrorKind::LeadingZero => write!(f, "forbidden leading symbol {}", Symbol(byte))?,
+            AzamErrorKind::Truncated => write!(f, "unexpected end of section")?,
+            AzamErrorKind::Overflow => write!(f, "section value does not fit into target type")?,
+            AzamErrorKind::InvalidSection => write!(f, "invalid section for target type")?,
+            AzamErrorKind::InvalidUtf8 => write!(f, "invalid UTF-8 in text section")?,
+            AzamErrorKind::NonCanonical => write!(f, "non-canonical symbol {}", Symbol(byte))?,
+            AzamErrorKind::LimitExceeded => write!(f, "decode limit exceeded")?,
+            AzamErrorKind::TrailingData => write!(f, "trailing data {}", Symbol(byte))?,
+            AzamErrorKind::BufferTooSmall => return write!(f, "destination buffer too small"),
+            AzamErrorKind::Unsupported => return write!(f, "unsupported: {}", self.message_str()),
+            AzamErrorKind::Custom => return write!(f, "{}", self.message_str()),
+            AzamErrorKind::Io => {
+                #[cfg(feature = "std")]
+                if let Some(source) = &self.source {
+                    return write!(f, "I/O error: {}", source);
+                }
+                return write!(f, "I/O error");
+            }
+        }
+        write!(f, " at offset {} in section {}", self.offset, self.section)?;
+        #[cfg(feature = "alloc")]
+        if let Some(input) = &self.input {
+            // Cut ends of input are marked with ellipses
+            let head = if self.input_start > 0 { "..." } else { "" };
+            let tail = if self.input_start + input.len() < self.input_len {
+                "..."
+            } else {
+                ""
+            };
+            // Count characters instead of bytes, so the caret lines up with multi-byte input
+            let offset = self.offset.saturating_sub(self.input_start);
+            let column = head.len()
+                + input
+                    .get(..offset)
+                    .map_or(offset, |prefix| prefix.chars().count());
+            write!(
+                f,
+                "\n  {}{}{}\n  {:>width$}",
+                head,
+                input,
+                tail,
+                "^",
+                width = column + 1
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl AzamError {
+    /// Returns the message to display, which is empty without `alloc` feature.
+    fn message_str(&self) -> &str {
+        #[cfg(feature = "alloc")]
+        if let Some(message) = &self.message {
+            return message;
+        }
+        ""
+    }
+}
+
+/// Display a byte as a quoted character if printable, otherwise as hex.
+struct Symbol(u8);
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_ascii_graphic() {
+            write!(f, "'{}'", self.0 as char)
+        } else {
+            write!(f, "0x{:02x}", self.0)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AzamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl From<AzamErrorKind> for AzamError {
+    fn from(kind: AzamErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for AzamError {
+    fn from(err: io::Error) -> Self {
+        // Errors of Write implementations of this crate, e.g. AzamBuf, are passed through as is
+        if err.get_ref().is_some_and(|inner| inner.is::<AzamError>()) {
+            return *err.into_inner().unwrap().downcast::<AzamError>().unwrap();
+        }
+        match err.kind() {
+            // Running out of input always means the section is truncated
+            io::ErrorKind::UnexpectedEof => Self::new(AzamErrorKind::Truncated),
+            _ => Self {
+                source: Some(err),
+                ..Self::new(AzamErrorKind::Io)
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<AzamError> for io::Error {
+    fn from(err: AzamError) -> Self {
+        let kind = match err.kind {
+            AzamErrorKind::Io => match err.source {
+                Some(source) => return source,
+                None => io::ErrorKind::Other,
+            },
+            AzamErrorKind::Truncated => io::ErrorKind::UnexpectedEof,
+            AzamErrorKind::BufferTooSmall => io::ErrorKind::WriteZero,
+            _ => io::ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use crate::decode::AzamDecode;
+    use crate::error::*;
+    use std::string::ToString;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_error_display() {
+        let err = u32::azam_decode("xy_txvyyf").unwrap_err();
+        assert_eq!(
+            "invalid symbol '_' at offset 2 in section 0\n  xy_txvyyf\n    ^",
+            err.to_string()
+        );
+        let err = u16::azam_decode("gzzf").unwrap_err();
+        assert_eq!(
+            "forbidden leading symbol 'g' at offset 0 in section 0\n  gzzf\n  ^",
+            err.to_string()
+        );
+        let err = crate::azam_decode!("xytxvyyfh5wg", u32, u8, u16).unwrap_err();
+        assert_eq!(
+            "unexpected end of section at offset 12 in section 2\n  xytxvyyfh5wg\n              ^",
+            err.to_string()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_error_input_window() {
+        let value = std::format!("{}_{}", "z".repeat(1 << 20), "f");
+        let err = u8::azam_decode(&value).unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSymbol, err.kind());
+        assert_eq!(1 << 20, err.offset());
+        assert_eq!((1 << 20) - 32, err.input_start());
+        assert_eq!(std::format!("{}_f", "z".repeat(32)), err.input().unwrap());
+        assert_eq!(
+            std::format!(
+                "invalid symbol '_' at offset {} in section 0\n  ...{}_f\n  {:>36}",
+                1 << 20,
+                "z".repeat(32),
+                "^"
+            ),
+            err.to_string()
+        );
+        let err = u8::azam_decode(&std::format!("_{}", "z".repeat(100))).unwrap_err();
+        assert_eq!(0, err.input_start());
+        assert_eq!(32, err.input().unwrap().len());
+        assert!(err.to_string().ends_with("zz...\n  ^"));
+    }
+
+    #[test]
+    fn test_azam_error_display_position() {
+        let err = AzamError::new(AzamErrorKind::InvalidSymbol)
+            .with_byte(0x0a)
+            .at(3, 1);
+        assert_eq!(
+            "invalid symbol 0x0a at offset 3 in section 1",
+            err.to_string()
+        );
+        assert_eq!(AzamErrorKind::InvalidSymbol, err.kind());
+        assert_eq!(Some(0x0a), err.byte());
+        let err = AzamError::from(AzamErrorKind::BufferTooSmall);
+        assert_eq!("destination buffer too small", err.to_string());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_error_position() {
+        let err = crate::azam_decode!("xytxvyyfh5w_g1", u32, u8, u16).unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSymbol, err.kind());
+        assert_eq!(11, err.offset());
+        assert_eq!(2, err.section());
+        assert_eq!(Some(b'_'), err.byte());
+        assert_eq!(Some("xytxvyyfh5w_g1"), err.input());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_error_io() {
+        let err = AzamError::from(io::Error::from(io::ErrorKind::UnexpectedEof));
+        assert_eq!(AzamErrorKind::Truncated, err.kind());
+        let err = AzamError::from(io::Error::from(io::ErrorKind::BrokenPipe));
+        assert_eq!(AzamErrorKind::Io, err.kind());
+        assert_eq!(io::ErrorKind::BrokenPipe, io::Error::from(err).kind());
+        let err = io::Error::from(u8::azam_decode("_").unwrap_err());
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        let err = io::Error::from(u8::azam_decode("h").unwrap_err());
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+        let err = AzamError::from(io::Error::from(u8::azam_decode("_").unwrap_err()));
+        assert_eq!(AzamErrorKind::InvalidSymbol, err.kind());
+        assert_eq!(Some(b'_'), err.byte());
+    }
+}
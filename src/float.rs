@@ -0,0 +1,82 @@
+use crate::decode::{AzamDecode, AzamReader};
+use crate::encode::{AzamEncode, AzamEncodedLen};
+use crate::error::Result;
+use std::io::{Read, Write};
+
+/// Wrapper of floating point value, which encodes all NaN values as the same canonical NaN, and -0.0 as +0.0.
+///
+/// Plain [`f32`] and [`f64`] encodings are bit-exact and sort the same as `total_cmp`, so NaN payloads and signed zeros produce different strings.
+/// Use this wrapper when encoded strings are compared for equality, e.g. as lookup keys.
+/// Decoded values are canonicalized as well.
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::{encode::AzamEncode, float::CanonicalFloat};
+///
+/// assert_eq!(CanonicalFloat(0.0f64).azam_encode(), CanonicalFloat(-0.0f64).azam_encode());
+/// assert_eq!(
+///     CanonicalFloat(f64::NAN).azam_encode(),
+///     CanonicalFloat(-f64::NAN).azam_encode()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct CanonicalFloat<T>(pub T);
+
+macro_rules! canonical_float_impl {
+    ($t:ty) => {
+        impl CanonicalFloat<$t> {
+            /// Returns the canonical value, which is positive quiet NaN for all NaN values, +0.0 for -0.0, and the value itself otherwise.
+            pub fn canonical(self) -> $t {
+                match self.0 {
+                    value if value.is_nan() => <$t>::NAN.copysign(1.0),
+                    // -0.0 == 0.0, so this also matches -0.0
+                    0.0 => 0.0,
+                    value => value,
+                }
+            }
+        }
+
+        impl AzamEncode for CanonicalFloat<$t> {
+            fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+                self.canonical().azam_encode_write(writer)
+            }
+
+            fn azam_encode(&self) -> String {
+                self.canonical().azam_encode()
+            }
+        }
+
+        impl AzamEncodedLen for CanonicalFloat<$t> {
+            const MAX_ENCODED_LEN: usize = <$t>::MAX_ENCODED_LEN;
+        }
+
+        impl AzamDecode for CanonicalFloat<$t> {
+            fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+                let value = <$t>::azam_decode_from(reader)?;
+                Ok(Self(Self(value).canonical()))
+            }
+        }
+    };
+}
+
+canonical_float_impl!(f32);
+canonical_float_impl!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::decode::AzamDecode;
+    use crate::encode::AzamEncode;
+    use crate::float::*;
+
+    #[test]
+    fn test_canonical_float() {
+        assert_eq!(0.0f64.azam_encode(), CanonicalFloat(-0.0f64).azam_encode());
+        assert_eq!(1.5f32.azam_encode(), CanonicalFloat(1.5f32).azam_encode());
+        let nan = f64::from_bits(0xfff8_dead_beef_0001);
+        assert_eq!(f64::NAN.azam_encode(), CanonicalFloat(nan).azam_encode());
+        let decoded = CanonicalFloat::<f64>::azam_decode(&nan.azam_encode()).unwrap();
+        assert_eq!(f64::NAN.to_bits(), decoded.0.to_bits());
+        let decoded = CanonicalFloat::<f32>::azam_decode(&(-0.0f32).azam_encode()).unwrap();
+        assert_eq!(0.0f32.to_bits(), decoded.0.to_bits());
+    }
+}
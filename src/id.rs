@@ -0,0 +1,217 @@
+use crate::azam::Azam;
+use crate::decode::{AzamDecode, AzamReader};
+use crate::encode::{AzamEncode, AzamEncodedLen};
+use crate::error::{AzamError, Result};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Identifier of entity `T`, which is a raw value of type `Repr` encoded as Azam codec.
+///
+/// `T` is only a marker, usually an empty enum, so identifiers of different entities are different types.
+/// Comparing, ordering or hashing them together does not compile, and neither does passing one where another is expected.
+/// Conversions to and from the raw value are explicit, see [`AzamId::from_raw`] and [`AzamId::raw`].
+///
+/// Identifiers display and parse as Azam codec encoded string, and encode and decode the same as the raw value, so they can be fields of composite keys.
+/// Equality, ordering and hashing are those of the raw value, see [`Azam`] for ordering by encoded string.
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::id::AzamId;
+///
+/// enum User {}
+/// enum Order {}
+///
+/// let user = AzamId::<User>::from_raw(0xdeadbeef);
+/// let order: AzamId<Order, u32> = "xytxvyyf".parse().unwrap();
+/// assert_eq!("xytxvyyf", user.to_string());
+/// assert_eq!(0xdeadbeef, order.raw());
+/// ```
+///
+/// Identifiers of different entities cannot be compared.
+/// ```compile_fail
+/// use azamcodec::id::AzamId;
+///
+/// enum User {}
+/// enum Order {}
+///
+/// let user = AzamId::<User>::from_raw(1);
+/// let order = AzamId::<Order>::from_raw(1);
+/// assert!(user == order);
+/// ```
+pub struct AzamId<T: ?Sized, Repr = u64> {
+    raw: Repr,
+    // Identifiers do not own an entity, so auto traits and variance do not depend on T
+    entity: PhantomData<fn() -> T>,
+}
+
+impl<T: ?Sized, Repr> AzamId<T, Repr> {
+    /// Create an identifier of given raw value.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - Raw value of identifier
+    pub const fn from_raw(raw: Repr) -> Self {
+        Self {
+            raw,
+            entity: PhantomData,
+        }
+    }
+
+    /// Returns the raw value of identifier.
+    pub fn into_raw(self) -> Repr {
+        self.raw
+    }
+
+    /// Returns a reference to the raw value of identifier.
+    pub const fn as_raw(&self) -> &Repr {
+        &self.raw
+    }
+}
+
+impl<T: ?Sized, Repr: Copy> AzamId<T, Repr> {
+    /// Returns the raw value of identifier.
+    pub const fn raw(&self) -> Repr {
+        self.raw
+    }
+}
+
+impl<T: ?Sized, Repr: Clone> Clone for AzamId<T, Repr> {
+    fn clone(&self) -> Self {
+        Self::from_raw(self.raw.clone())
+    }
+}
+
+impl<T: ?Sized, Repr: Copy> Copy for AzamId<T, Repr> {}
+
+impl<T: ?Sized, Repr: PartialEq> PartialEq for AzamId<T, Repr> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl<T: ?Sized, Repr: Eq> Eq for AzamId<T, Repr> {}
+
+impl<T: ?Sized, Repr: PartialOrd> PartialOrd for AzamId<T, Repr> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.raw.partial_cmp(&other.raw)
+    }
+}
+
+impl<T: ?Sized, Repr: Ord> Ord for AzamId<T, Repr> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl<T: ?Sized, Repr: Hash> Hash for AzamId<T, Repr> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state)
+    }
+}
+
+impl<T: ?Sized, Repr: AzamEncode> fmt::Display for AzamId<T, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.raw.azam_encode_fmt(f)
+    }
+}
+
+/// Debug output has both the encoded string and raw value, e.g. `AzamId("xytxvyyf", 3735928559)`.
+impl<T: ?Sized, Repr: AzamEncode + fmt::Debug> fmt::Debug for AzamId<T, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AzamId")
+            .field(&format_args!("\"{}\"", self))
+            .field(&self.raw)
+            .finish()
+    }
+}
+
+/// Parse a string of a single encoded value, and reject any trailing data with an [`AzamErrorKind::TrailingData`](crate::error::AzamErrorKind::TrailingData) error.
+impl<T: ?Sized, Repr: AzamDecode> FromStr for AzamId<T, Repr> {
+    type Err = AzamError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        value.parse::<Azam<Repr>>().map(|id| Self::from_raw(id.0))
+    }
+}
+
+impl<T: ?Sized, Repr: AzamDecode> TryFrom<&str> for AzamId<T, Repr> {
+    type Error = AzamError;
+
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+/// Encode the same as the raw value.
+impl<T: ?Sized, Repr: AzamEncode> AzamEncode for AzamId<T, Repr> {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        self.raw.azam_encode_write(writer)
+    }
+
+    fn azam_encode(&self) -> String {
+        self.raw.azam_encode()
+    }
+}
+
+impl<T: ?Sized, Repr: AzamEncodedLen> AzamEncodedLen for AzamId<T, Repr> {
+    const MAX_ENCODED_LEN: usize = Repr::MAX_ENCODED_LEN;
+}
+
+impl<T: ?Sized, Repr: AzamDecode> AzamDecode for AzamId<T, Repr> {
+    fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+        Repr::azam_decode_from(reader).map(Self::from_raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AzamErrorKind;
+    use crate::id::*;
+    use std::collections::{BTreeMap, HashSet};
+
+    enum User {}
+    enum Order {}
+
+    #[test]
+    fn test_azam_id() {
+        let user = AzamId::<User>::from_raw(0xdeadbeef);
+        assert_eq!(0xdeadbeef, user.raw());
+        assert_eq!(&0xdeadbeef, user.as_raw());
+        assert_eq!("xytxvyyf", user.to_string());
+        assert_eq!("xytxvyyf", user.azam_encode());
+        assert_eq!("AzamId(\"xytxvyyf\", 3735928559)", format!("{:?}", user));
+        assert_eq!(user, "xytxvyyf".parse().unwrap());
+        assert_eq!(user, AzamId::try_from("xytxvyyf").unwrap());
+        let order = AzamId::<Order, u16>::azam_decode("wgg1").unwrap();
+        assert_eq!(0xc001, order.into_raw());
+
+        let err = "xytxvyyfh5".parse::<AzamId<User>>().unwrap_err();
+        assert_eq!(AzamErrorKind::TrailingData, err.kind());
+        let err = "h5".parse::<AzamId<Order, i32>>().unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSection, err.kind());
+    }
+
+    #[test]
+    fn test_azam_id_key() {
+        let key = (
+            AzamId::<User>::from_raw(1),
+            AzamId::<Order, u8>::from_raw(0x15),
+        );
+        assert_eq!("1h5", key.azam_encode());
+        assert_eq!(
+            key,
+            <(AzamId<User>, AzamId<Order, u8>)>::azam_decode("1h5").unwrap()
+        );
+
+        let mut orders = BTreeMap::new();
+        orders.insert(AzamId::<Order>::from_raw(0x100), "b");
+        orders.insert(AzamId::<Order>::from_raw(0xff), "a");
+        assert_eq!(vec!["a", "b"], orders.values().copied().collect::<Vec<_>>());
+        let users = HashSet::from([AzamId::<User>::from_raw(1), AzamId::from_raw(1)]);
+        assert_eq!(1, users.len());
+    }
+}
@@ -0,0 +1,458 @@
+#[cfg(feature = "std")]
+use crate::decode::section_len;
+#[cfg(feature = "std")]
+use crate::decode::AzamDecode;
+#[cfg(feature = "std")]
+use crate::encode::AzamEncode;
+use crate::error::{AzamError, Result};
+#[cfg(feature = "std")]
+use crate::view::decode_section;
+use crate::view::AzamView;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Range;
+use core::str::FromStr;
+
+/// Owned Azam codec encoded key, which is edited section by section.
+///
+/// Sections are spliced as encoded text, so editing a section neither decodes nor re-encodes the others, and sections such as byte-strings with leading zero bytes are kept as is.
+/// Values are encoded with [`AzamEncode`], so a value may add multiple sections, e.g. a tuple, or none, e.g. `()`.
+/// Equality, ordering and hashing are those of the encoded string.
+///
+/// # Examples
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use azamcodec::key::AzamKey;
+///
+/// let mut key = AzamKey::new();
+/// key.push(&0xdeadbeefu32).push(&0x15u8).push(&1u16);
+/// key.set_section(2, &2u16);
+/// assert_eq!("xytxvyyfh52", key.as_str());
+///
+/// // Parent key is a prefix of sections
+/// let mut parent = key.clone();
+/// parent.truncate(2);
+/// assert_eq!("xytxvyyfh5", parent.as_str());
+/// assert!(parent < key);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AzamKey {
+    value: String,
+    ends: Vec<usize>,
+}
+
+impl AzamKey {
+    /// Create a new key without sections.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the Azam codec encoded string.
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the Azam codec encoded string, consuming the key.
+    pub fn into_string(self) -> String {
+        self.value
+    }
+
+    /// Returns the number of sections.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns true if there are no sections.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns a borrowed view of this key, see [`AzamView`].
+    pub fn view(&self) -> AzamView<'_> {
+        AzamView::from_parts(&self.value, self.ends.clone())
+    }
+
+    /// Returns the section at given index, or None if out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of section
+    pub fn section(&self, index: usize) -> Option<&str> {
+        self.section_range(index).map(|range| &self.value[range])
+    }
+
+    /// Returns an iterator of all sections.
+    pub fn sections(&self) -> impl ExactSizeIterator<Item = &str> + DoubleEndedIterator + '_ {
+        (0..self.len()).map(|index| &self.value[self.section_range(index).unwrap()])
+    }
+
+    /// Decode the section at given index, see [`AzamView::decode_section`].
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of section
+    #[cfg(feature = "std")]
+    pub fn decode_section<T: AzamDecode>(&self, index: usize) -> Result<T> {
+        decode_section(&self.value, index, self.len(), self.section_range(index))
+    }
+
+    /// Append encoded value as new sections.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to append
+    ///
+    /// # Panics
+    ///
+    /// Panics if the encoded value is not complete sections, which only happens with a faulty [`AzamEncode`] implementation.
+    #[cfg(feature = "std")]
+    pub fn push<T: AzamEncode + ?Sized>(&mut self, value: &T) -> &mut Self {
+        let len = self.len();
+        self.splice_value(len..len, value);
+        self
+    }
+
+    /// Replace the section at given index with encoded value.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of section
+    /// * `value` - Value to replace the section with
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range, or the encoded value is not complete sections.
+    #[cfg(feature = "std")]
+    pub fn set_section<T: AzamEncode + ?Sized>(&mut self, index: usize, value: &T) -> &mut Self {
+        self.check_index(index, self.len());
+        self.splice_value(index..index + 1, value);
+        self
+    }
+
+    /// Insert encoded value as new sections before the section at given index, or at the end if index is the number of sections.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of section
+    /// * `value` - Value to insert
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of sections, or the encoded value is not complete sections.
+    #[cfg(feature = "std")]
+    pub fn insert<T: AzamEncode + ?Sized>(&mut self, index: usize, value: &T) -> &mut Self {
+        self.check_index(index, self.len() + 1);
+        self.splice_value(index..index, value);
+        self
+    }
+
+    /// Remove the section at given index, and return it as Azam codec encoded string.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of section
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range.
+    pub fn remove(&mut self, index: usize) -> String {
+        self.check_index(index, self.len());
+        let range = self.section_range(index).unwrap_or_default();
+        let removed = self.value.drain(range.clone()).collect();
+        self.ends.remove(index);
+        for end in &mut self.ends[index..] {
+            *end -= range.len();
+        }
+        removed
+    }
+
+    /// Keep the first `count` sections and remove the rest, which has no effect if there are not more sections.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of sections to keep
+    pub fn truncate(&mut self, count: usize) {
+        if count < self.len() {
+            self.value.truncate(self.byte_start(count));
+            self.ends.truncate(count);
+        }
+    }
+
+    /// Append all sections of a view.
+    ///
+    /// # Arguments
+    ///
+    /// * `view` - View of sections to append
+    pub fn extend_from(&mut self, view: &AzamView<'_>) -> &mut Self {
+        let start = self.value.len();
+        self.value.push_str(view.as_str());
+        self.ends.extend(
+            (0..view.len())
+                .filter_map(|index| view.section_range(index))
+                .map(|range| start + range.end),
+        );
+        self
+    }
+
+    /// Returns the byte range of section at given index, or None if out of range.
+    fn section_range(&self, index: usize) -> Option<Range<usize>> {
+        let end = *self.ends.get(index)?;
+        Some(self.byte_start(index)..end)
+    }
+
+    /// Returns the byte offset where the section at given index starts, which may be the end of the string.
+    fn byte_start(&self, index: usize) -> usize {
+        index.checked_sub(1).map_or(0, |prev| self.ends[prev])
+    }
+
+    fn check_index(&self, index: usize, len: usize) {
+        assert!(
+            index < len,
+            "section index (is {}) should be < len (is {})",
+            index,
+            len
+        );
+    }
+
+    /// Replace given range of sections with encoded value, which is written straight into the string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if encoding fails or the encoded value is not complete sections, in which case the key is left unchanged.
+    #[cfg(feature = "std")]
+    fn splice_value<T: AzamEncode + ?Sized>(&mut self, sections: Range<usize>, value: &T) {
+        let start = self.byte_start(sections.start);
+        let end = self.byte_start(sections.end);
+        let len = self.value.len();
+        // Encoded value is appended first, and moved into place once it is known to be complete sections
+        if value.azam_encode_fmt(&mut self.value).is_err() {
+            self.value.truncate(len);
+            panic!("failed to encode value");
+        }
+        let mut ends = Vec::<usize>::new();
+        let mut index = len;
+        while index < self.value.len() {
+            match section_len(&self.value.as_bytes()[index..]) {
+                Ok(count) => index += count,
+                Err(err) => {
+                    self.value.truncate(len);
+                    panic!("encoded value should be complete sections: {}", err);
+                }
+            }
+            ends.push(index - len + end);
+        }
+        let encoded_len = self.value.len() - len;
+        if end < len {
+            // All symbols are ASCII, so rotating bytes keeps the string valid
+            let mut bytes = core::mem::take(&mut self.value).into_bytes();
+            bytes[end..].rotate_right(encoded_len);
+            self.value = String::from_utf8(bytes).unwrap();
+        }
+        self.value.replace_range(start..end, "");
+        for old in &mut self.ends[sections.end..] {
+            *old = *old + encoded_len - (end - start);
+        }
+        self.ends
+            .splice(sections, ends.into_iter().map(|new| new - (end - start)));
+    }
+}
+
+impl From<&AzamView<'_>> for AzamKey {
+    fn from(view: &AzamView<'_>) -> Self {
+        let mut key = Self::new();
+        key.extend_from(view);
+        key
+    }
+}
+
+/// Parse a string of complete sections, see [`AzamView::new`].
+impl FromStr for AzamKey {
+    type Err = AzamError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        AzamView::new(value).map(|view| Self::from(&view))
+    }
+}
+
+impl TryFrom<&str> for AzamKey {
+    type Error = AzamError;
+
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl AsRef<str> for AzamKey {
+    fn as_ref(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for AzamKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+impl PartialEq for AzamKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for AzamKey {}
+
+impl PartialOrd for AzamKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AzamKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl Hash for AzamKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AzamErrorKind;
+    use crate::key::*;
+    use std::{string::ToString, vec, vec::Vec};
+
+    #[test]
+    fn test_azam_key_sections() {
+        let mut key: AzamKey = "xytxvyyfhgghn0wgg1".parse().unwrap();
+        assert_eq!(
+            vec!["xytxvyyf", "hgghn0", "wgg1"],
+            key.sections().collect::<Vec<_>>()
+        );
+        assert_eq!("hgghn0", key.remove(1));
+        assert_eq!("xytxvyyfwgg1", key.to_string());
+        key.extend_from(&AzamView::new("h5").unwrap());
+        assert_eq!(Some("h5"), key.section(2));
+        key.truncate(1);
+        assert_eq!("xytxvyyf", key.as_str());
+        assert_eq!(key, AzamKey::from(&key.view()));
+        let err = AzamKey::try_from("h5w_g1").unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSymbol, err.kind());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_key_push() {
+        let mut key = AzamKey::new();
+        assert!(key.is_empty());
+        key.push(&0xdeadbeefu32)
+            .push(&(0x15u8, 0xc001u16))
+            .push(&());
+        assert_eq!("xytxvyyfh5wgg1", key.as_str());
+        assert_eq!(3, key.len());
+        assert_eq!(
+            vec!["xytxvyyf", "h5", "wgg1"],
+            key.sections().collect::<Vec<_>>()
+        );
+        assert_eq!(Some("h5"), key.section(1));
+        assert_eq!(0xc001u16, key.decode_section::<u16>(2).unwrap());
+        assert_eq!(key.view(), AzamView::new("xytxvyyfh5wgg1").unwrap());
+        assert_eq!("xytxvyyfh5wgg1", key.to_string());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_key_edit() {
+        let mut key: AzamKey = "xytxvyyfhgghn0wgg1".parse().unwrap();
+        assert_eq!(3, key.len());
+        key.set_section(2, &2u16);
+        assert_eq!("xytxvyyfhgghn02", key.as_str());
+        // Byte-string section with leading zero byte is kept as is
+        assert_eq!(
+            vec![0x00u8, 0x15],
+            key.decode_section::<Vec<u8>>(1).unwrap()
+        );
+        key.insert(1, &-1i8);
+        assert_eq!("xytxvyyfqzfhgghn02", key.as_str());
+        assert_eq!(-1i8, key.decode_section::<i8>(1).unwrap());
+        assert_eq!(2u16, key.decode_section::<u16>(3).unwrap());
+        key.insert(4, &(1u8, 2u8));
+        assert_eq!(6, key.len());
+        assert_eq!("hgghn0", key.remove(2));
+        assert_eq!("xytxvyyfqzf212", key.as_str());
+        key.set_section(1, &());
+        assert_eq!("xytxvyyf212", key.as_str());
+        assert_eq!(
+            vec!["xytxvyyf", "2", "1", "2"],
+            key.sections().collect::<Vec<_>>()
+        );
+
+        key.truncate(2);
+        assert_eq!("xytxvyyf2", key.as_str());
+        key.truncate(5);
+        assert_eq!(2, key.len());
+        key.truncate(0);
+        assert!(key.is_empty());
+        assert_eq!("", key.as_str());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_key_extend() {
+        let view = AzamView::new("h5wgg1").unwrap();
+        let mut key = AzamKey::try_from("xytxvyyf").unwrap();
+        key.extend_from(&view).push(&1u8);
+        assert_eq!("xytxvyyfh5wgg11", key.as_str());
+        assert_eq!(Some("wgg1"), key.section(2));
+        assert_eq!(AzamKey::from(&view).into_string(), "h5wgg1");
+
+        let parent = {
+            let mut parent = key.clone();
+            parent.truncate(1);
+            parent
+        };
+        assert!(parent < key);
+        assert!(key.as_str().starts_with(parent.as_str()));
+
+        let err = "xytxvyyfh5wgg".parse::<AzamKey>().unwrap_err();
+        assert_eq!(AzamErrorKind::Truncated, err.kind());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic]
+    fn test_azam_key_set_section_out_of_range() {
+        AzamKey::new().push(&1u8).set_section(1, &2u8);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "encoded value should be complete sections")]
+    fn test_azam_key_push_incomplete() {
+        struct Incomplete;
+
+        impl AzamEncode for Incomplete {
+            fn azam_encode_write<W: std::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> crate::error::Result<usize> {
+                writer.write_all(b"wgg")?;
+                Ok(3)
+            }
+
+            fn azam_encode(&self) -> String {
+                "wgg".to_string()
+            }
+        }
+
+        AzamKey::new().push(&1u8).push(&Incomplete);
+    }
+}
@@ -1,5 +1,5 @@
 /*!
-An encoder and decoder implementation in Rust for [Azam Codec](https://github.com/azam/azamcodec), a lexicographically sortable multi-section base16 encoding of byte array. Zero external dependencies.
+An encoder and decoder implementation in Rust for [Azam Codec](https://github.com/azam/azamcodec), a lexicographically sortable multi-section base16 encoding of byte array. No dependencies by default, and optional `derive` and `serde` features add their dependencies.
 
 ## License
 
@@ -13,7 +13,8 @@ Import the crate and start using it.
 ### Decoding
 
 ```rust
-use azamcodec::{azam_decode, decode::AzamDecode};
+# #[cfg(feature = "std")] {
+use azamcodec::{azam_decode, azam_decode_exact, decode::AzamDecode};
 
 // Decode first section of Azam-encoded string as u32, using trait [`AzamDecode`] on uint type.
 // "xytxvyyf" decodes to 0xdeadbeefu32, the rest of string is ignored.
@@ -25,6 +26,10 @@ let x = u32::azam_decode("xytxvyyfh5wgg1"); // 0xdeadbeefu32
 // "wgg1" decodes to 0xc001u16.
 let (x, y, z) = azam_decode!("xytxvyyfh5wgg1", u32, u8, u16).unwrap(); // (0xdeadbeefu32, 0x15u8, c001u16)
 
+// Decode the whole string, which fails with trailing data error if any section is left unconsumed.
+let (x, y, z) = azam_decode_exact!("xytxvyyfh5wgg1", u32, u8, u16).unwrap(); // (0xdeadbeefu32, 0x15u8, c001u16)
+let err = u32::azam_decode_exact("xytxvyyfh5wgg1").unwrap_err(); // trailing data 'h' at offset 8
+
 // Decode multiple sections of Azam-encoded string into custom struct.
 struct Id {
     record_id: u32,
@@ -47,11 +52,13 @@ impl Id {
         }
     }
 }
+# }
 ```
 
 ### Encoding
 
 ```rust
+# #[cfg(feature = "std")] {
 use azamcodec::{azam_encode, encode::AzamEncode};
 
 // Encode u32 value as Azam-encoded string as u32, using trait [`AzamEncode`] on uint type.
@@ -82,6 +89,137 @@ impl Id {
         String::from_utf8(writer).unwrap()
     }
 }
+# }
+```
+
+### Byte arrays
+
+Byte arrays encoded as uint sections, e.g. with `azam_encode_slice` and `azam_encode_bytes`, drop leading zero bytes, and an empty byte array cannot be encoded.
+Empty input fails with a `Truncated` error, and `azam_encode_bytes` panics, since `"0"` already encodes `[0x00]`.
+To keep the exact length, including empty byte arrays, encode as byte-string sections, e.g. with `azam_encode_byte_string_slice` or `AzamEncode` of `Vec<u8>`.
+
+```rust
+# #[cfg(feature = "std")] {
+use azamcodec::encode::{azam_encode_slice, AzamEncode};
+
+let mut dst = [0u8; 8];
+let err = azam_encode_slice(&[], &mut dst).unwrap_err(); // unexpected end of section
+let x = Vec::<u8>::new().azam_encode(); // "h0"
+let x = vec![0x00u8, 0x15].azam_encode(); // "hgghn0"
+# }
+```
+
+### Azam wrapper
+
+`Azam` wraps a value to display, parse, compare and hash it as Azam-encoded string, e.g. as a map key or in log lines.
+
+```rust
+# #[cfg(feature = "std")] {
+use azamcodec::Azam;
+
+let line = format!("id={}", Azam(&(0xdeadbeefu32, 0x15u8, 0xc001u16))); // "id=xytxvyyfh5wgg1"
+let id: Azam<u32> = "xytxvyyf".parse().unwrap(); // Azam(0xdeadbeefu32)
+# }
+```
+
+### Allocation-free encoding
+
+Values with a bounded encoded length can be encoded to a fixed capacity `AzamBuf` on the stack, which dereferences to `str`.
+
+```rust
+# #[cfg(feature = "std")] {
+use azamcodec::{buf::AzamBuf, encode::{max_encoded_len, AzamEncode}};
+
+let x: AzamBuf<{ max_encoded_len::<(u32, u8, u16)>() }> =
+    (0xdeadbeefu32, 0x15u8, 0xc001u16).azam_encode_to_buf().unwrap(); // "xytxvyyfh5wgg1"
+# }
+```
+
+### Untrusted input
+
+`DecodeOptions` bounds section length, number of sections and input length, and configures canonical symbols, separators and trailing data handling.
+
+```rust
+# #[cfg(feature = "std")] {
+use azamcodec::options::DecodeOptions;
+
+const OPTIONS: DecodeOptions = DecodeOptions::UNTRUSTED.max_sections(3).separators(b"-");
+let (x, y, z) = OPTIONS.decode::<(u32, u8, u16)>("xytxvyyf-h5-wgg1").unwrap(); // (0xdeadbeefu32, 0x15u8, 0xc001u16)
+# }
+```
+
+### no_std
+
+The crate is `no_std` compatible when default features are disabled.
+Slice based functions, e.g. `azam_encode_slice` and `azam_decode_slice`, are always available.
+Functions returning `Vec` and `String` require `alloc` feature, and `AzamEncode`/`AzamDecode` traits and other `std::io` based functions require `std` feature, which is enabled by default.
+
+```toml
+[dependencies]
+azamcodec = { version = "0.1", default-features = false, features = ["alloc"] }
+```
+
+### Derive
+
+With `derive` feature enabled, `AzamEncode` and `AzamDecode` can be derived for structs and enums.
+Types of encoded fields are bounded by the derived trait, instead of all type parameters, and `#[azam(crate = "...")]` sets the path of this crate when it is renamed or re-exported.
+
+```toml
+[dependencies]
+azamcodec = { version = "0.1", features = ["derive"] }
+```
+
+*/
+#![cfg_attr(feature = "derive", doc = "```rust")]
+#![cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
+/*!
+use azamcodec::{decode::AzamDecode, encode::AzamEncode};
+
+// Fields are encoded as consecutive sections, in field order.
+#[derive(AzamEncode, AzamDecode)]
+struct Id {
+    record_id: u32,
+    type_id: u8,
+    variant_id: u16,
+    // Field sorts in descending order.
+    #[azam(desc)]
+    revision: i32,
+    // Field is not encoded, and decoded as Default::default().
+    #[azam(skip)]
+    cache: Option<String>,
+}
+
+// Enums are encoded as variant index followed by variant fields, so variants sort in declaration order.
+#[derive(AzamEncode, AzamDecode)]
+enum Key {
+    Empty,
+    Single(u32),
+    Pair { left: u32, right: u32 },
+}
+```
+
+### Serde
+
+With `serde` feature enabled, any type implementing `serde::Serialize` and `serde::Deserialize` can be encoded as a sortable Azam-encoded string.
+Integers, strings, options, sequences, tuples, structs and enums are supported. Maps are not supported, since Azam codec is not self-describing.
+Fields are positional, so `#[serde(skip_serializing_if = "...")]` is rejected with an error, while `#[serde(skip)]` is supported.
+
+*/
+#![cfg_attr(feature = "serde", doc = "```rust")]
+#![cfg_attr(not(feature = "serde"), doc = "```rust,ignore")]
+/*!
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct Id {
+    record_id: u32,
+    type_id: u8,
+    variant_id: u16,
+}
+
+let id = Id { record_id: 0xdeadbeef, type_id: 0x15, variant_id: 0xc001 };
+let encoded = azamcodec::to_string(&id).unwrap(); // "xytxvyyfh5wgg1"
+let decoded = azamcodec::from_str::<Id>(&encoded).unwrap();
 ```
 
 ## Development
@@ -90,9 +228,94 @@ Standard Rust development applies. Benchmark is also included, executable via `c
  */
 
 #![deny(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+// Tests run on the host, so they can use std even without `std` feature
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+
+/// Wrapper types for Azam codec
+#[cfg(feature = "std")]
+pub mod azam;
+
+/// Fixed capacity buffer for Azam codec
+pub mod buf;
+
+/// Canonical form of Azam codec
+pub mod canonical;
 
 /// Decoding functions for Azam codec
 pub mod decode;
 
 /// Encoding functions for Azam codec
 pub mod encode;
+
+/// Error types for Azam codec
+pub mod error;
+
+/// Typed identifiers for Azam codec
+#[cfg(feature = "std")]
+pub mod id;
+
+/// Floating point helpers for Azam codec
+#[cfg(feature = "std")]
+pub mod float;
+
+/// Decoding options for Azam codec
+#[cfg(feature = "std")]
+pub mod options;
+
+/// Owned key of Azam codec encoded sections
+#[cfg(feature = "alloc")]
+pub mod key;
+
+/// Option helpers for Azam codec
+#[cfg(feature = "std")]
+pub mod option;
+
+/// Zero-copy view of sections of Azam codec encoded string
+#[cfg(feature = "alloc")]
+pub mod view;
+
+/// Serde data format and field adapters for Azam codec, available with `serde` feature
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "std")]
+pub use crate::azam::Azam;
+
+#[cfg(feature = "serde")]
+pub use crate::serde::{from_str, to_string};
+
+/// Support functions of derive macros, which are not part of public API.
+#[doc(hidden)]
+#[cfg(feature = "std")]
+pub mod __private {
+    use crate::decode::{read_ordered_section, AzamReader};
+    use crate::encode::OrderedSection;
+    use crate::error::{AzamError, AzamErrorKind, Result};
+    use std::io::{Read, Write};
+
+    /// Encode enum variant index as an ordered section, so variants sort in declaration order.
+    pub fn encode_variant<W: Write>(index: u32, writer: &mut W) -> Result<usize> {
+        OrderedSection::unsigned(index as u128).write(writer)
+    }
+
+    /// Check that the reader has no more input, which is used by [`azam_decode_exact!`](crate::azam_decode_exact).
+    pub fn finish<R: Read>(reader: &mut AzamReader<R>) -> Result<()> {
+        reader.finish()
+    }
+
+    /// Decode enum variant index, which must be less than `count`.
+    pub fn decode_variant<R: Read>(reader: &mut AzamReader<R>, count: u32) -> Result<u32> {
+        let (offset, section) = (reader.offset(), reader.section());
+        match read_ordered_section(reader)? {
+            // Payload of a section longer than u32 would have lost its high bits
+            (false, index, len) if len <= 8 && index < count as u128 => Ok(index as u32),
+            _ => Err(AzamError::new(AzamErrorKind::InvalidSection).at(offset, section)),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::decode::{AzamDecode, AzamReader};
+use crate::encode::{AzamEncode, AzamEncodedLen};
+use crate::error::{AzamError, AzamErrorKind, Result};
+use std::cmp::Ordering;
+use std::io::{Read, Write};
+
+/// Wrapper of [`Option`], which encodes [`None`] to sort after all [`Some`] values.
+///
+/// [`Some`] is encoded the same as [`Option`], as a single low nybble 1 section followed by the value.
+/// [`None`] is encoded as a single low nybble 2 section, instead of 0.
+/// [`Ord`] matches the encoded order, so [`None`] is greater than any [`Some`] value, unlike [`Option`].
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::{encode::AzamEncode, option::NoneLast};
+///
+/// let none = NoneLast::<u32>(None).azam_encode(); // "2"
+/// let some = NoneLast(Some(u32::MAX)).azam_encode(); // "1zzzzzzzf"
+/// assert!(some < none);
+/// assert!(NoneLast(Some(u32::MAX)) < NoneLast(None));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NoneLast<T>(pub Option<T>);
+
+impl<T> From<Option<T>> for NoneLast<T> {
+    fn from(value: Option<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for NoneLast<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (&self.0, &other.0) {
+            (Some(left), Some(right)) => left.partial_cmp(right),
+            (left, right) => Some(left.is_none().cmp(&right.is_none())),
+        }
+    }
+}
+
+impl<T: Ord> Ord for NoneLast<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (Some(left), Some(right)) => left.cmp(right),
+            (left, right) => left.is_none().cmp(&right.is_none()),
+        }
+    }
+}
+
+impl<T: AzamEncode> AzamEncode for NoneLast<T> {
+    fn azam_encode_write<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        match &self.0 {
+            None => 2u8.azam_encode_write(writer),
+            some => some.azam_encode_write(writer),
+        }
+    }
+
+    fn azam_encode(&self) -> String {
+        match &self.0 {
+            None => 2u8.azam_encode(),
+            some => some.azam_encode(),
+        }
+    }
+}
+
+impl<T: AzamEncodedLen> AzamEncodedLen for NoneLast<T> {
+    const MAX_ENCODED_LEN: usize = Option::<T>::MAX_ENCODED_LEN;
+}
+
+impl<T: AzamDecode> AzamDecode for NoneLast<T> {
+    fn azam_decode_from<R: Read>(reader: &mut AzamReader<R>) -> Result<Self> {
+        let (offset, section) = (reader.offset(), reader.section());
+        match reader.read_nybble()? {
+            0x01u8 => T::azam_decode_from(reader).map(|value| Self(Some(value))),
+            0x02u8 => Ok(Self(None)),
+            _ => {
+                reader.skip_section()?;
+                Err(AzamError::new(AzamErrorKind::InvalidSection).at(offset, section))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::decode::AzamDecode;
+    use crate::encode::AzamEncode;
+    use crate::error::AzamErrorKind;
+    use crate::option::*;
+
+    #[test]
+    fn test_none_last() {
+        assert_eq!("2", NoneLast::<u8>(None).azam_encode());
+        assert_eq!("1h5", NoneLast(Some(0x15u8)).azam_encode());
+        assert!(NoneLast(Some(u128::MAX)).azam_encode() < NoneLast::<u128>(None).azam_encode());
+        assert_eq!(
+            NoneLast(Some(0x15u8)),
+            NoneLast::<u8>::azam_decode("1h5").unwrap()
+        );
+        assert_eq!(NoneLast(None), NoneLast::<u8>::azam_decode("2").unwrap());
+        assert_eq!(
+            AzamErrorKind::InvalidSection,
+            NoneLast::<u8>::azam_decode("0").unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn test_none_last_order() {
+        let mut values = [
+            NoneLast(Some(0x15u32)),
+            NoneLast(None),
+            NoneLast(Some(u32::MAX)),
+            NoneLast(Some(0)),
+            NoneLast(None),
+        ];
+        let mut encoded = values
+            .iter()
+            .map(AzamEncode::azam_encode)
+            .collect::<Vec<_>>();
+        values.sort();
+        encoded.sort();
+        assert_eq!(
+            encoded,
+            values
+                .iter()
+                .map(AzamEncode::azam_encode)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(NoneLast(None), values[4]);
+        assert!(NoneLast(Some(-1.0f64)) < NoneLast(None));
+        assert!(NoneLast(Some(1u8)) > NoneLast(Some(0u8)));
+    }
+}
@@ -0,0 +1,320 @@
+//! [`DecodeOptions`](crate::options::DecodeOptions) configures how [`AzamReader`](crate::decode::AzamReader) reads Azam codec encoded input, which is useful to bound resources spent on untrusted input, e.g. identifiers in HTTP headers.
+//!
+//! Existing decoding functions are presets of options:
+//!
+//! * [`AzamDecode::azam_decode`](crate::decode::AzamDecode::azam_decode), [`azam_decode!`](crate::azam_decode) and [`azam_decode_bytes_vec`](crate::decode::azam_decode_bytes_vec) use [`DecodeOptions::LENIENT`](crate::options::DecodeOptions::LENIENT), which has no limits, accepts aliases and ignores trailing data.
+//! * [`AzamDecode::azam_decode_exact`](crate::decode::AzamDecode::azam_decode_exact) and [`azam_decode_exact!`](crate::azam_decode_exact) use [`DecodeOptions::EXACT`](crate::options::DecodeOptions::EXACT), which rejects trailing data.
+//! * [`AzamDecode::azam_decode_strict`](crate::decode::AzamDecode::azam_decode_strict) uses [`DecodeOptions::STRICT`](crate::options::DecodeOptions::STRICT), which rejects non-canonical symbols.
+//! * [`azam_decode_read_until`](crate::decode::azam_decode_read_until) is similar to [`DecodeOptions::max_input_len`](crate::options::DecodeOptions::max_input_len), which reports an [`AzamErrorKind::LimitExceeded`](crate::error::AzamErrorKind::LimitExceeded) error instead of a truncated section.
+//!
+//! [`DecodeOptions::UNTRUSTED`](crate::options::DecodeOptions::UNTRUSTED) is a starting point for untrusted input.
+
+use crate::decode::{nybble_value, AzamDecode, AzamReader};
+use crate::error::{AzamErrorKind, Result};
+use std::io::Read;
+
+/// Options of decoding Azam codec encoded input, which are built from presets with const builder methods.
+///
+/// Limits are checked while reading, so decoding stops with an [`AzamErrorKind::LimitExceeded`] error as soon as a limit is exceeded, before any unbounded buffer is allocated.
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::options::DecodeOptions;
+///
+/// const OPTIONS: DecodeOptions = DecodeOptions::EXACT
+///     .max_section_len(16)
+///     .max_sections(3)
+///     .separators(b"-");
+///
+/// let (x, y, z) = OPTIONS.decode::<(u32, u8, u16)>("xytxvyyf-h5-wgg1").unwrap(); // (0xdeadbeefu32, 0x15u8, 0xc001u16)
+/// assert!(OPTIONS.decode::<u128>("zzzzzzzzzzzzzzzzf").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecodeOptions {
+    pub(crate) max_section_len: Option<usize>,
+    pub(crate) max_sections: Option<usize>,
+    pub(crate) max_input_len: Option<usize>,
+    pub(crate) strict: bool,
+    pub(crate) exact: bool,
+    pub(crate) separators: &'static [u8],
+}
+
+impl DecodeOptions {
+    /// No limits, aliases and uppercase letters are accepted, no separators are allowed, and trailing data is ignored.
+    pub const LENIENT: Self = Self {
+        max_section_len: None,
+        max_sections: None,
+        max_input_len: None,
+        strict: false,
+        exact: false,
+        separators: &[],
+    };
+
+    /// Same as [`DecodeOptions::LENIENT`], except that the whole input must be consumed.
+    pub const EXACT: Self = Self::LENIENT.exact(true);
+
+    /// Same as [`DecodeOptions::LENIENT`], except that only canonical symbols are accepted.
+    pub const STRICT: Self = Self::LENIENT.strict(true);
+
+    /// Canonical input of up to 8 sections of 34 symbols each, which fits any primitive value, and 256 bytes in total, which must be fully consumed.
+    pub const UNTRUSTED: Self = Self::LENIENT
+        .max_section_len(34)
+        .max_sections(8)
+        .max_input_len(256)
+        .strict(true)
+        .exact(true);
+
+    /// Create options of [`DecodeOptions::LENIENT`] preset.
+    pub const fn new() -> Self {
+        Self::LENIENT
+    }
+
+    /// Set the maximum number of symbols of each section.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Maximum number of symbols
+    pub const fn max_section_len(mut self, len: usize) -> Self {
+        self.max_section_len = Some(len);
+        self
+    }
+
+    /// Set the maximum number of sections.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Maximum number of sections
+    pub const fn max_sections(mut self, count: usize) -> Self {
+        self.max_sections = Some(count);
+        self
+    }
+
+    /// Set the maximum number of bytes of input, including separators.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Maximum number of bytes
+    pub const fn max_input_len(mut self, len: usize) -> Self {
+        self.max_input_len = Some(len);
+        self
+    }
+
+    /// Set whether only canonical symbols are accepted, see [`canonical`](crate::canonical).
+    /// Non-canonical symbols are rejected with an [`AzamErrorKind::NonCanonical`] error.
+    /// Sections longer than the target type, e.g. an ordered section of 33 symbols for [`char`], are rejected regardless of this option.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - True to reject aliases and uppercase letters
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Set whether the whole input must be consumed.
+    /// Unconsumed input is rejected with an [`AzamErrorKind::TrailingData`] error.
+    ///
+    /// # Arguments
+    ///
+    /// * `exact` - True to reject trailing data
+    pub const fn exact(mut self, exact: bool) -> Self {
+        self.exact = exact;
+        self
+    }
+
+    /// Set the bytes allowed between sections, which are skipped while reading, e.g. `b"-"` for `"xytxvyyf-h5-wgg1"`.
+    /// Separators must not be valid symbols, and are not allowed before the first section or after the last section.
+    ///
+    /// # Arguments
+    ///
+    /// * `separators` - Bytes allowed between sections
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of `separators` is a valid symbol, which fails to compile in const context.
+    pub const fn separators(mut self, separators: &'static [u8]) -> Self {
+        let mut i = 0;
+        while i < separators.len() {
+            assert!(
+                nybble_value(separators[i]).is_none(),
+                "separators must not be valid symbols"
+            );
+            i += 1;
+        }
+        self.separators = separators;
+        self
+    }
+
+    /// Create an [`AzamReader`] of these options.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - Azam codec encoded stream
+    pub fn reader<R: Read>(&self, inner: R) -> AzamReader<R> {
+        AzamReader::with_options(inner, *self)
+    }
+
+    /// Decode a value from a [`Read`] instance of Azam codec encoded stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Azam codec encoded stream
+    pub fn decode_read<T: AzamDecode, R: Read>(&self, reader: R) -> Result<T> {
+        let mut reader = self.reader(reader);
+        let value = T::azam_decode_from(&mut reader)?;
+        if self.exact {
+            reader.finish()?;
+        }
+        Ok(value)
+    }
+
+    /// Decode a value from an Azam codec encoded string.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Azam codec encoded string
+    pub fn decode<T: AzamDecode>(&self, value: &str) -> Result<T> {
+        self.decode_read(value.as_bytes())
+            .map_err(|err| err.with_input(value))
+    }
+
+    /// Decode all sections of an Azam codec encoded string, and return as array of byte array.
+    /// This is the same as [`azam_decode_bytes_vec`](crate::decode::azam_decode_bytes_vec) with [`DecodeOptions::LENIENT`], and the whole input is always consumed.
+    /// Separators after the last section are rejected with an [`AzamErrorKind::TrailingData`] error, same as [`DecodeOptions::decode`] with [`DecodeOptions::exact`].
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Azam codec encoded string
+    ///
+    /// # Examples
+    /// ```rust
+    /// use azamcodec::options::DecodeOptions;
+    ///
+    /// let decoded = DecodeOptions::UNTRUSTED.decode_bytes_vec("xytxvyyfh5wgg1").unwrap();
+    /// // decoded = vec![vec![0xde, 0xad, 0xbe, 0xef], vec![0x15], vec![0xc0, 0x01]]
+    /// ```
+    pub fn decode_bytes_vec(&self, value: &str) -> Result<Vec<Vec<u8>>> {
+        let mut reader = self.reader(value.as_bytes());
+        let mut all_bytes = Vec::<Vec<u8>>::new();
+        while let Some(&byte) = value.as_bytes().get(reader.offset()) {
+            // Separators after the last section are trailing data, same as decode
+            let rest = &value.as_bytes()[reader.offset()..];
+            if reader.section() > 0 && rest.iter().all(|b| self.separators.contains(b)) {
+                let err = reader.error(AzamErrorKind::TrailingData).with_byte(byte);
+                return Err(err.with_input(value));
+            }
+            let mut bytes = Vec::<u8>::new();
+            reader
+                .read_section(&mut bytes)
+                .map_err(|err| err.with_input(value))?;
+            all_bytes.push(bytes);
+        }
+        Ok(all_bytes)
+    }
+}
+
+impl Default for DecodeOptions {
+    fn default() -> Self {
+        Self::LENIENT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::decode::azam_decode_bytes_vec;
+    use crate::error::AzamErrorKind;
+    use crate::options::*;
+
+    #[test]
+    fn test_decode_options_presets() {
+        assert_eq!(
+            0xffu8,
+            DecodeOptions::LENIENT.decode::<u8>("ZFaaaa").unwrap()
+        );
+        assert_eq!(DecodeOptions::LENIENT, DecodeOptions::default());
+        let err = DecodeOptions::EXACT.decode::<u8>("zfaaaa").unwrap_err();
+        assert_eq!(AzamErrorKind::TrailingData, err.kind());
+        assert_eq!(2, err.offset());
+        let err = DecodeOptions::STRICT.decode::<u8>("ZF").unwrap_err();
+        assert_eq!(AzamErrorKind::NonCanonical, err.kind());
+        for value in ["xytxvyyfh5wgg1", "", "XYoL", "xytx_", "h5wgg"] {
+            let expected = azam_decode_bytes_vec(value).map_err(|err| err.kind());
+            let actual = DecodeOptions::LENIENT
+                .decode_bytes_vec(value)
+                .map_err(|err| err.kind());
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn test_decode_options_limits() {
+        let options = DecodeOptions::new().max_section_len(4);
+        assert_eq!(0xffffu16, options.decode::<u16>("zzzf").unwrap());
+        let err = options.decode::<u32>("zzzzf").unwrap_err();
+        assert_eq!(AzamErrorKind::LimitExceeded, err.kind());
+        assert_eq!(4, err.offset());
+        assert_eq!(0, err.section());
+
+        let options = DecodeOptions::new().max_sections(2);
+        assert_eq!((1u8, 2u8), options.decode::<(u8, u8)>("123").unwrap());
+        let err = options.decode_bytes_vec("123").unwrap_err();
+        assert_eq!(AzamErrorKind::LimitExceeded, err.kind());
+        assert_eq!(2, err.offset());
+        assert_eq!(2, err.section());
+
+        let options = DecodeOptions::new().max_input_len(8);
+        assert_eq!(0xdeadbeefu32, options.decode::<u32>("xytxvyyfh5").unwrap());
+        let err = options.decode::<(u32, u8)>("xytxvyyfh5").unwrap_err();
+        assert_eq!(AzamErrorKind::LimitExceeded, err.kind());
+        assert_eq!(8, err.offset());
+
+        let err = DecodeOptions::UNTRUSTED
+            .decode_bytes_vec(&"1".repeat(1 << 20))
+            .unwrap_err();
+        assert_eq!(AzamErrorKind::LimitExceeded, err.kind());
+        assert_eq!(8, err.offset());
+        let err = DecodeOptions::UNTRUSTED
+            .decode::<Vec<u8>>(&format!("h{}0", "z".repeat(1 << 20)))
+            .unwrap_err();
+        assert_eq!(AzamErrorKind::LimitExceeded, err.kind());
+        assert_eq!(34, err.offset());
+    }
+
+    #[test]
+    fn test_decode_options_separators() {
+        let options = DecodeOptions::EXACT.separators(b"-.");
+        assert_eq!(
+            (0xdeadbeefu32, 0x15u8, 0xc001u16),
+            options.decode("xytxvyyf-h5.wgg1").unwrap()
+        );
+        assert_eq!(
+            vec![vec![0x15u8], vec![0x01u8]],
+            options.decode_bytes_vec("h5--1").unwrap()
+        );
+        let err = options.decode::<(u8, u8)>("h-51").unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSymbol, err.kind());
+        assert_eq!(1, err.offset());
+        let err = options.decode::<u8>("-1").unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSymbol, err.kind());
+        let err = options.decode::<u8>("1-").unwrap_err();
+        assert_eq!(AzamErrorKind::TrailingData, err.kind());
+        assert_eq!(1, err.offset());
+        let err = options.decode_bytes_vec("h5-.").unwrap_err();
+        assert_eq!(AzamErrorKind::TrailingData, err.kind());
+        assert_eq!(2, err.offset());
+        assert_eq!(Some("h5-."), err.input());
+        let err = DecodeOptions::EXACT.decode::<(u8, u8)>("1-1").unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSymbol, err.kind());
+
+        let options = DecodeOptions::new().separators(b"-").max_input_len(3);
+        let err = options.decode::<(u8, u8)>("1--1").unwrap_err();
+        assert_eq!(AzamErrorKind::LimitExceeded, err.kind());
+        assert_eq!(3, err.offset());
+    }
+
+    #[test]
+    #[should_panic(expected = "separators must not be valid symbols")]
+    fn test_decode_options_separators_symbol() {
+        DecodeOptions::EXACT.separators(b"-1");
+    }
+}
@@ -0,0 +1,227 @@
+//! Serde support for Azam codec, available with `serde` feature.
+//!
+//! [`Serializer`](crate::serde::Serializer) and [`Deserializer`](crate::serde::Deserializer) implement Azam codec as a serde data format, so any type implementing [`Serialize`](::serde::Serialize) can be encoded as a sortable Azam codec string.
+//! Values are mapped to the same sections as [`AzamEncode`](crate::encode::AzamEncode) implementations:
+//!
+//! * Unsigned integers are encoded as uint sections, and signed integers, floats and chars as ordered sections.
+//! * Strings and byte arrays are encoded as byte-string sections.
+//! * Structs, tuples and tuple structs are encoded as consecutive sections of their fields.
+//! * Options and sequences are encoded as optional values and sequences of [`AzamEncode`](crate::encode::AzamEncode), so shorter sequences and [`None`] sort first.
+//! * Enum variants are encoded as an ordered section of variant index, followed by the variant fields, so variants sort in declaration order.
+//!
+//! Azam codec is not self-describing, so maps and `deserialize_any` are not supported, and fail with an [`AzamErrorKind::Unsupported`](crate::error::AzamErrorKind::Unsupported) error.
+//! Fields are positional, so fields skipped conditionally with `#[serde(skip_serializing_if = "...")]` fail with the same error, since decoding would shift all following fields.
+//! Fields skipped with `#[serde(skip)]` are fine, since they are skipped on both sides.
+//! Sequences of [`u8`] are encoded element by element, unless serialized as bytes, e.g. with `serde_bytes`.
+//!
+//! Separately from the data format, [`as_azam`](crate::serde::as_azam) renders fields of other formats, e.g. JSON, as Azam codec encoded strings.
+
+pub mod as_azam;
+mod de;
+mod ser;
+
+pub use self::de::{from_reader, from_str, Deserializer};
+pub use self::ser::{to_string, to_writer, Serializer};
+
+use crate::error::{AzamError, AzamErrorKind};
+use std::fmt::Display;
+
+impl ::serde::ser::Error for AzamError {
+    fn custom<T: Display>(msg: T) -> Self {
+        AzamError::new(AzamErrorKind::Custom).with_message(msg)
+    }
+}
+
+impl ::serde::de::Error for AzamError {
+    fn custom<T: Display>(msg: T) -> Self {
+        AzamError::new(AzamErrorKind::Custom).with_message(msg)
+    }
+}
+
+/// Create an error for a shape of value that Azam codec cannot represent.
+fn unsupported(what: &str) -> AzamError {
+    AzamError::new(AzamErrorKind::Unsupported).with_message(what)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encode::AzamEncode;
+    use crate::error::AzamErrorKind;
+    use crate::serde::*;
+    use ::serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Id {
+        record_id: u32,
+        type_id: u8,
+        variant_id: u16,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Key {
+        tenant: String,
+        shard: Option<i32>,
+        path: Vec<String>,
+        kind: Kind,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Kind {
+        File,
+        Link(i64),
+        Mount { device: u8, read_only: bool },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Unit;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Sparse {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        first: Option<u8>,
+        #[serde(skip)]
+        cache: u8,
+        last: u8,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Pair(i8, char);
+
+    #[test]
+    fn test_serde_to_string() {
+        let id = Id {
+            record_id: 0xdeadbeef,
+            type_id: 0x15,
+            variant_id: 0xc001,
+        };
+        assert_eq!("xytxvyyfh5wgg1", to_string(&id).unwrap());
+        assert_eq!((-17i64).azam_encode(), to_string(&-17i64).unwrap());
+        assert_eq!(1.5f64.azam_encode(), to_string(&1.5f64).unwrap());
+        assert_eq!("ab".azam_encode(), to_string("ab").unwrap());
+        assert_eq!(Some(3u8).azam_encode(), to_string(&Some(3u8)).unwrap());
+        assert_eq!(
+            (-1i8, 'A').azam_encode(),
+            to_string(&Pair(-1, 'A')).unwrap()
+        );
+        assert_eq!("", to_string(&Unit).unwrap());
+        assert_eq!("r0", to_string(&Kind::File).unwrap());
+        assert_eq!("rh1rh5", to_string(&Kind::Link(5)).unwrap());
+
+        let mut dst = Vec::<u8>::new();
+        to_writer(&mut dst, &id).unwrap();
+        assert_eq!(b"xytxvyyfh5wgg1", dst.as_slice());
+    }
+
+    #[test]
+    fn test_serde_from_str() {
+        let id = from_str::<Id>("xytxvyyfh5wgg1").unwrap();
+        assert_eq!(0xdeadbeef, id.record_id);
+        assert_eq!(0x15, id.type_id);
+        assert_eq!(0xc001, id.variant_id);
+        assert_eq!(Unit, from_str::<Unit>("").unwrap());
+        assert_eq!(Pair(-1, 'A'), from_str::<Pair>("qzfrjm1").unwrap());
+
+        let keys = [
+            Key {
+                tenant: "acme".to_string(),
+                shard: None,
+                path: vec![],
+                kind: Kind::File,
+            },
+            Key {
+                tenant: "acme".to_string(),
+                shard: Some(-2),
+                path: vec!["a".to_string(), "b".to_string()],
+                kind: Kind::Link(-100),
+            },
+            Key {
+                tenant: "acme".to_string(),
+                shard: Some(7),
+                path: vec!["a".to_string()],
+                kind: Kind::Mount {
+                    device: 3,
+                    read_only: true,
+                },
+            },
+        ];
+        for key in keys.iter() {
+            assert_eq!(key, &from_str::<Key>(&to_string(key).unwrap()).unwrap());
+        }
+        assert_eq!(
+            keys[2],
+            from_reader::<_, Key>(to_string(&keys[2]).unwrap().as_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_serde_order() {
+        let mut values = vec![
+            (Some(-5i32), "b".to_string(), vec![1i16]),
+            (None, "z".to_string(), vec![]),
+            (Some(-5i32), "a".to_string(), vec![1i16, -1]),
+            (Some(10i32), "a".to_string(), vec![]),
+            (Some(-5i32), "a".to_string(), vec![1i16]),
+        ];
+        let mut encoded = values
+            .iter()
+            .map(|value| to_string(value).unwrap())
+            .collect::<Vec<_>>();
+        values.sort();
+        encoded.sort();
+        let decoded = encoded
+            .iter()
+            .map(|value| from_str(value).unwrap())
+            .collect::<Vec<(Option<i32>, String, Vec<i16>)>>();
+        assert_eq!(values, decoded);
+    }
+
+    #[test]
+    fn test_serde_errors() {
+        let map = BTreeMap::from([(1u8, 2u8)]);
+        let err = to_string(&map).unwrap_err();
+        assert_eq!(AzamErrorKind::Unsupported, err.kind());
+        assert_eq!("unsupported: maps are not supported", err.to_string());
+        let err = from_str::<BTreeMap<u8, u8>>("12").unwrap_err();
+        assert_eq!(AzamErrorKind::Unsupported, err.kind());
+
+        let err = from_str::<Id>("xytxvyyfh5wgg1zz").unwrap_err();
+        assert_eq!(AzamErrorKind::TrailingData, err.kind());
+        assert_eq!(14, err.offset());
+        assert_eq!(Some(b'z'), err.byte());
+        assert_eq!(Some("xytxvyyfh5wgg1zz"), err.input());
+
+        let err = from_str::<Id>("xytxvyyfh5").unwrap_err();
+        assert_eq!(AzamErrorKind::Truncated, err.kind());
+        assert_eq!(2, err.section());
+
+        let err = from_str::<Kind>("rh3").unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSection, err.kind());
+        // Overlong variant index must not wrap into a valid index
+        let err = from_str::<Kind>(&format!("thh{}1rh5", "g".repeat(31))).unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSection, err.kind());
+        assert_eq!(0, err.offset());
+
+        let sparse = Sparse {
+            first: None,
+            cache: 1,
+            last: 2,
+        };
+        let err = to_string(&sparse).unwrap_err();
+        assert_eq!(AzamErrorKind::Unsupported, err.kind());
+        assert_eq!(
+            "unsupported: skipped field `first` is not supported",
+            err.to_string()
+        );
+        let sparse = Sparse {
+            first: Some(1),
+            cache: 1,
+            last: 2,
+        };
+        assert_eq!("112", to_string(&sparse).unwrap());
+        assert_eq!(
+            Sparse { cache: 0, ..sparse },
+            from_str::<Sparse>("112").unwrap()
+        );
+    }
+}
@@ -0,0 +1,244 @@
+//! Serde field adapters, which serialize values implementing [`AzamEncode`] and [`AzamDecode`] as Azam codec encoded strings.
+//!
+//! Use with `#[serde(with = "azamcodec::serde::as_azam")]`, e.g. to render `u64` identifiers of JSON payloads as strings, which JavaScript clients do not truncate.
+//! Composite keys are tuples, which are encoded as a single string of consecutive sections.
+//! [`option`](mod@option) and [`vec`](mod@vec) adapt [`Option`] and [`Vec`] fields of such values.
+//!
+//! Deserialization requires the whole string to be a single encoded value, and errors report the offending string.
+//!
+//! # Examples
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Order {
+//!     #[serde(with = "azamcodec::serde::as_azam")]
+//!     id: u64,
+//!     #[serde(with = "azamcodec::serde::as_azam")]
+//!     key: (u32, u8, u16),
+//!     #[serde(with = "azamcodec::serde::as_azam::option")]
+//!     parent: Option<u64>,
+//!     #[serde(with = "azamcodec::serde::as_azam::vec")]
+//!     items: Vec<u32>,
+//! }
+//!
+//! let order = Order {
+//!     id: 0xdeadbeef,
+//!     key: (0xdeadbeef, 0x15, 0xc001),
+//!     parent: None,
+//!     items: vec![1, 2],
+//! };
+//! let json = serde_json::to_string(&order).unwrap();
+//! // {"id":"xytxvyyf","key":"xytxvyyfh5wgg1","parent":null,"items":["1","2"]}
+//! ```
+
+use crate::decode::AzamDecode;
+use crate::encode::AzamEncode;
+use ::serde::de::{Error, Visitor};
+use ::serde::{Deserialize, Deserializer, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Decode a string of a single encoded value, reporting the offending string on error.
+fn decode_str<T: AzamDecode, E: Error>(value: &str) -> Result<T, E> {
+    T::azam_decode_exact(value).map_err(|err| {
+        E::custom(format_args!(
+            "invalid Azam codec string {:?}: {}",
+            value, err
+        ))
+    })
+}
+
+/// Value decoded from an Azam codec encoded string, which is decoded from the string the deserializer lends without copying it.
+#[repr(transparent)]
+struct Decoded<T>(T);
+
+impl<'de, T: AzamDecode> Deserialize<'de> for Decoded<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(DecodedVisitor(PhantomData))
+    }
+}
+
+struct DecodedVisitor<T>(PhantomData<T>);
+
+impl<T: AzamDecode> Visitor<'_> for DecodedVisitor<T> {
+    type Value = Decoded<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an Azam codec encoded string")
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        decode_str(value).map(Decoded)
+    }
+}
+
+/// Serialize value as an Azam codec encoded string.
+///
+/// # Arguments
+///
+/// * `value` - Value to serialize
+/// * `serializer` - Serde serializer
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AzamEncode + ?Sized,
+    S: Serializer,
+{
+    serializer.serialize_str(&value.azam_encode())
+}
+
+/// Deserialize value from an Azam codec encoded string.
+///
+/// # Arguments
+///
+/// * `deserializer` - Serde deserializer
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: AzamDecode,
+    D: Deserializer<'de>,
+{
+    Decoded::deserialize(deserializer).map(|decoded| decoded.0)
+}
+
+/// Serde field adapters of [`Option`], which serialize [`None`] as none of the format, e.g. `null` of JSON, and [`Some`] as an Azam codec encoded string.
+pub mod option {
+    use super::Decoded;
+    use crate::decode::AzamDecode;
+    use crate::encode::AzamEncode;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serialize optional value as none, or an Azam codec encoded string.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to serialize
+    /// * `serializer` - Serde serializer
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AzamEncode,
+        S: Serializer,
+    {
+        match value {
+            None => serializer.serialize_none(),
+            Some(value) => serializer.serialize_some(&value.azam_encode()),
+        }
+    }
+
+    /// Deserialize optional value from none, or an Azam codec encoded string.
+    ///
+    /// # Arguments
+    ///
+    /// * `deserializer` - Serde deserializer
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: AzamDecode,
+        D: Deserializer<'de>,
+    {
+        Option::<Decoded<T>>::deserialize(deserializer).map(|value| value.map(|decoded| decoded.0))
+    }
+}
+
+/// Serde field adapters of [`Vec`], which serialize each value as an Azam codec encoded string.
+pub mod vec {
+    use super::Decoded;
+    use crate::decode::AzamDecode;
+    use crate::encode::AzamEncode;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serialize values as a sequence of Azam codec encoded strings.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - Values to serialize
+    /// * `serializer` - Serde serializer
+    pub fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AzamEncode,
+        S: Serializer,
+    {
+        serializer.collect_seq(values.iter().map(AzamEncode::azam_encode))
+    }
+
+    /// Deserialize values from a sequence of Azam codec encoded strings.
+    ///
+    /// # Arguments
+    ///
+    /// * `deserializer` - Serde deserializer
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        T: AzamDecode,
+        D: Deserializer<'de>,
+    {
+        // Decoded is transparent, so collecting reuses the allocation
+        Vec::<Decoded<T>>::deserialize(deserializer)
+            .map(|values| values.into_iter().map(|decoded| decoded.0).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        #[serde(with = "crate::serde::as_azam")]
+        id: u64,
+        #[serde(with = "crate::serde::as_azam")]
+        key: (u32, u8, u16),
+        #[serde(with = "crate::serde::as_azam::option")]
+        parent: Option<u128>,
+        #[serde(with = "crate::serde::as_azam::vec")]
+        items: Vec<i32>,
+    }
+
+    #[test]
+    fn test_as_azam() {
+        let order = Order {
+            id: 0xdeadbeef,
+            key: (0xdeadbeef, 0x15, 0xc001),
+            parent: Some(0x15),
+            items: vec![-1, 0],
+        };
+        let json = serde_json::to_string(&order).unwrap();
+        assert_eq!(
+            r#"{"id":"xytxvyyf","key":"xytxvyyfh5wgg1","parent":"h5","items":["qzf","r0"]}"#,
+            json
+        );
+        assert_eq!(order, serde_json::from_str::<Order>(&json).unwrap());
+
+        // Escaped strings are unescaped into a buffer, and readers lend no string
+        let json = r#"{"id":"\u0031","key":"11h0","parent":"h5","items":["r0"]}"#;
+        let order = serde_json::from_reader::<_, Order>(json.as_bytes()).unwrap();
+        assert_eq!(
+            (1, Some(0x15), vec![0]),
+            (order.id, order.parent, order.items)
+        );
+
+        let json = r#"{"id":"1","key":"11h0","parent":null,"items":[]}"#;
+        let order = serde_json::from_str::<Order>(json).unwrap();
+        assert_eq!((1, 1, 0x10), order.key);
+        assert_eq!(None, order.parent);
+        assert_eq!(
+            r#"{"id":"1","key":"11h0","parent":null,"items":[]}"#,
+            serde_json::to_string(&order).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_as_azam_errors() {
+        let json = r#"{"id":"xy_txvyyf","key":"11h0","parent":null,"items":[]}"#;
+        let err = serde_json::from_str::<Order>(json).unwrap_err().to_string();
+        assert!(err.starts_with(
+            r#"invalid Azam codec string "xy_txvyyf": invalid symbol '_' at offset 2 in section 0"#
+        ));
+        let json = r#"{"id":"1","key":"11h0","parent":"h5junk","items":[]}"#;
+        let err = serde_json::from_str::<Order>(json).unwrap_err().to_string();
+        assert!(err.starts_with(r#"invalid Azam codec string "h5junk": trailing data 'j'"#));
+        let json = r#"{"id":"1","key":"11h0","parent":null,"items":["r0","rh"]}"#;
+        let err = serde_json::from_str::<Order>(json).unwrap_err().to_string();
+        assert!(err.starts_with(r#"invalid Azam codec string "rh": unexpected end of section"#));
+        let json = r#"{"id":1,"key":"11h0","parent":null,"items":[]}"#;
+        let err = serde_json::from_str::<Order>(json).unwrap_err().to_string();
+        assert!(err.starts_with("invalid type: integer `1`, expected an Azam codec encoded string"));
+    }
+}
@@ -0,0 +1,287 @@
+use crate::__private::decode_variant;
+use crate::decode::{AzamDecode, AzamReader};
+use crate::error::{AzamError, Result};
+use crate::serde::unsupported;
+use ::serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use std::io::Read;
+
+/// Serde deserializer, which reads values from an Azam codec encoded stream of a [`Read`] instance.
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::serde::Deserializer;
+/// use serde::Deserialize;
+///
+/// let mut deserializer = Deserializer::new("xytxvyyfh5wgg1".as_bytes());
+/// let (x, y, z) = <(u32, u8, u16)>::deserialize(&mut deserializer).unwrap(); // (0xdeadbeefu32, 0x15u8, 0xc001u16)
+/// deserializer.end().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Deserializer<R> {
+    reader: AzamReader<R>,
+}
+
+impl<R: Read> Deserializer<R> {
+    /// Create a new deserializer reading from a [`Read`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - Azam codec encoded stream
+    pub fn new(inner: R) -> Self {
+        Self {
+            reader: AzamReader::new(inner),
+        }
+    }
+
+    /// Check that the whole stream is consumed, or return an [`AzamErrorKind::TrailingData`](crate::error::AzamErrorKind::TrailingData) error.
+    pub fn end(&mut self) -> Result<()> {
+        self.reader.finish()
+    }
+
+    fn read<T: AzamDecode>(&mut self) -> Result<T> {
+        T::azam_decode_from(&mut self.reader)
+    }
+}
+
+/// Deserialize a value from an Azam codec encoded stream of [`Read`] instance, which must be fully consumed.
+///
+/// # Arguments
+///
+/// * `reader` - Azam codec encoded stream
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::serde::from_reader;
+///
+/// let (x, y, z) = from_reader::<_, (u32, u8, u16)>("xytxvyyfh5wgg1".as_bytes()).unwrap(); // (0xdeadbeefu32, 0x15u8, 0xc001u16)
+/// ```
+pub fn from_reader<R: Read, T: DeserializeOwned>(reader: R) -> Result<T> {
+    let mut deserializer = Deserializer::new(reader);
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/// Deserialize a value from an Azam codec encoded string, which must be fully consumed.
+///
+/// # Arguments
+///
+/// * `value` - Azam codec encoded string
+///
+/// # Examples
+/// ```rust
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Id {
+///     record_id: u32,
+///     type_id: u8,
+///     variant_id: u16,
+/// }
+///
+/// let id = azamcodec::from_str::<Id>("xytxvyyfh5wgg1").unwrap(); // Id { record_id: 0xdeadbeef, type_id: 0x15, variant_id: 0xc001 }
+/// ```
+pub fn from_str<T: DeserializeOwned>(value: &str) -> Result<T> {
+    from_reader(value.as_bytes()).map_err(|err| err.with_input(value))
+}
+
+macro_rules! deserialize_decode {
+    ($($method:ident => $visit:ident,)+) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                visitor.$visit(self.read()?)
+            }
+        )+
+    };
+}
+
+impl<'de, R: Read> de::Deserializer<'de> for &mut Deserializer<R> {
+    type Error = AzamError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(unsupported(
+            "Azam codec is not self-describing, type of value must be known",
+        ))
+    }
+
+    deserialize_decode! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+        deserialize_str => visit_string,
+        deserialize_string => visit_string,
+        deserialize_bytes => visit_byte_buf,
+        deserialize_byte_buf => visit_byte_buf,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.read::<bool>()? {
+            false => visitor.visit_none(),
+            true => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(SeqAccess {
+            deserializer: self,
+            len: None,
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(SeqAccess {
+            deserializer: self,
+            len: Some(len),
+        })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(unsupported("maps are not supported"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_tuple(fields.len(), visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let index = decode_variant(&mut self.reader, variants.len() as u32)?;
+        visitor.visit_enum(EnumAccess {
+            deserializer: self,
+            index,
+        })
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(unsupported("identifiers are not encoded"))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(unsupported(
+            "Azam codec is not self-describing, values cannot be skipped",
+        ))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// Access to elements of a sequence of unknown length, or a tuple or struct of known length.
+struct SeqAccess<'a, R> {
+    deserializer: &'a mut Deserializer<R>,
+    len: Option<usize>,
+}
+
+impl<'de, R: Read> de::SeqAccess<'de> for SeqAccess<'_, R> {
+    type Error = AzamError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        let next = match self.len.as_mut() {
+            // Sequences have a single low nybble 1 section before each element, and 0 at the end
+            None => self.deserializer.read::<bool>()?,
+            Some(0) => false,
+            Some(len) => {
+                *len -= 1;
+                true
+            }
+        };
+        match next {
+            false => Ok(None),
+            true => seed.deserialize(&mut *self.deserializer).map(Some),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.len
+    }
+}
+
+/// Access to an enum variant, which index has already been decoded.
+struct EnumAccess<'a, R> {
+    deserializer: &'a mut Deserializer<R>,
+    index: u32,
+}
+
+impl<'de, R: Read> de::EnumAccess<'de> for EnumAccess<'_, R> {
+    type Error = AzamError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self)> {
+        let index: de::value::U32Deserializer<AzamError> = self.index.into_deserializer();
+        Ok((seed.deserialize(index)?, self))
+    }
+}
+
+impl<'de, R: Read> de::VariantAccess<'de> for EnumAccess<'_, R> {
+    type Error = AzamError;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self.deserializer)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        de::Deserializer::deserialize_tuple(self.deserializer, len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        de::Deserializer::deserialize_tuple(self.deserializer, fields.len(), visitor)
+    }
+}
@@ -0,0 +1,358 @@
+use crate::__private::encode_variant;
+use crate::encode::AzamEncode;
+use crate::error::{AzamError, Result};
+use crate::serde::unsupported;
+use ::serde::ser::{self, Impossible, Serialize};
+use std::io::Write;
+
+/// Serde serializer, which writes values as Azam codec encoded stream to a [`Write`] instance.
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::serde::Serializer;
+/// use serde::Serialize;
+///
+/// let mut serializer = Serializer::new(Vec::<u8>::new());
+/// (0xdeadbeefu32, 0x15u8, 0xc001u16).serialize(&mut serializer).unwrap();
+/// let encoded = String::from_utf8(serializer.into_inner()).unwrap(); // "xytxvyyfh5wgg1"
+/// ```
+#[derive(Debug)]
+pub struct Serializer<W> {
+    writer: W,
+}
+
+impl<W: Write> Serializer<W> {
+    /// Create a new serializer writing to a [`Write`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Stream to write Azam codec encoded bytes
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Returns the underlying [`Write`] instance.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write<T: AzamEncode + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.azam_encode_write(&mut self.writer)?;
+        Ok(())
+    }
+}
+
+/// Serialize given value as Azam codec and write to [`Write`] instance.
+///
+/// # Arguments
+///
+/// * `writer` - Stream to write Azam codec encoded bytes
+/// * `value` - Value to serialize
+///
+/// # Examples
+/// ```rust
+/// use azamcodec::serde::to_writer;
+///
+/// let mut dst = Vec::<u8>::new();
+/// to_writer(&mut dst, &(0xdeadbeefu32, 0x15u8, 0xc001u16)).unwrap();
+/// let encoded = String::from_utf8(dst).unwrap(); // "xytxvyyfh5wgg1"
+/// ```
+pub fn to_writer<W: Write, T: Serialize + ?Sized>(writer: W, value: &T) -> Result<()> {
+    value.serialize(&mut Serializer::new(writer))
+}
+
+/// Serialize given value as Azam codec encoded string.
+///
+/// # Arguments
+///
+/// * `value` - Value to serialize
+///
+/// # Examples
+/// ```rust
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Id {
+///     record_id: u32,
+///     type_id: u8,
+///     variant_id: u16,
+/// }
+///
+/// let id = Id { record_id: 0xdeadbeef, type_id: 0x15, variant_id: 0xc001 };
+/// let encoded = azamcodec::to_string(&id).unwrap(); // "xytxvyyfh5wgg1"
+/// ```
+pub fn to_string<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    let mut encoded = Vec::<u8>::new();
+    to_writer(&mut encoded, value)?;
+    Ok(String::from_utf8(encoded).unwrap())
+}
+
+impl<W: Write> ser::Serializer for &mut Serializer<W> {
+    type Ok = ();
+    type Error = AzamError;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Impossible<(), AzamError>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.write(&v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.write(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.write(v)
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.write(&false)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+        self.write(&true)?;
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        encode_variant(variant_index, &mut self.writer)?;
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        encode_variant(variant_index, &mut self.writer)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self> {
+        encode_variant(variant_index, &mut self.writer)?;
+        Ok(self)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(unsupported("maps are not supported"))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self> {
+        encode_variant(variant_index, &mut self.writer)?;
+        Ok(self)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// Each element is preceded by a single low nybble 1 section, and the sequence ends with a single low nybble 0 section.
+impl<W: Write> ser::SerializeSeq for &mut Serializer<W> {
+    type Ok = ();
+    type Error = AzamError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.write(&true)?;
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        self.write(&false)
+    }
+}
+
+impl<W: Write> ser::SerializeTuple for &mut Serializer<W> {
+    type Ok = ();
+    type Error = AzamError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<W: Write> ser::SerializeTupleStruct for &mut Serializer<W> {
+    type Ok = ();
+    type Error = AzamError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<W: Write> ser::SerializeTupleVariant for &mut Serializer<W> {
+    type Ok = ();
+    type Error = AzamError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<W: Write> ser::SerializeStruct for &mut Serializer<W> {
+    type Ok = ();
+    type Error = AzamError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    /// Fields are positional, so a field skipped with `#[serde(skip_serializing_if)]` would shift all following fields.
+    fn skip_field(&mut self, key: &'static str) -> Result<()> {
+        Err(skipped_field(key))
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<W: Write> ser::SerializeStructVariant for &mut Serializer<W> {
+    type Ok = ();
+    type Error = AzamError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<()> {
+        Err(skipped_field(key))
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Create an error for a struct field skipped at runtime, e.g. with `#[serde(skip_serializing_if)]`.
+fn skipped_field(key: &str) -> AzamError {
+    unsupported(&format!("skipped field `{}` is not supported", key))
+}
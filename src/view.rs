@@ -0,0 +1,307 @@
+use crate::canonical::canonical_cmp;
+use crate::decode::section_len;
+#[cfg(feature = "std")]
+use crate::decode::AzamDecode;
+#[cfg(feature = "std")]
+use crate::error::AzamErrorKind;
+use crate::error::{AzamError, Result};
+#[cfg(feature = "std")]
+use crate::options::DecodeOptions;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::Range;
+
+/// Borrowed view of an Azam codec encoded string, which is validated once and records the boundaries of its sections.
+///
+/// Sections end at a low nybble symbol, the same as [`azam_decode_read`](crate::decode::azam_decode_read), so sections can be sliced, compared and decoded individually without decoding the sections before them.
+/// Equality, ordering and hashing are those of the whole string as given, so aliases and uppercase letters compare different from canonical symbols, see [`canonical`](crate::canonical).
+///
+/// # Examples
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use azamcodec::view::AzamView;
+///
+/// let view = AzamView::new("xytxvyyfh5wgg1").unwrap();
+/// assert_eq!(3, view.len());
+/// assert_eq!(Some("h5"), view.section(1));
+/// let z = view.decode_section::<u16>(2).unwrap(); // 0xc001u16
+/// let sections = view.sections().collect::<Vec<_>>(); // vec!["xytxvyyf", "h5", "wgg1"]
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AzamView<'a> {
+    value: &'a str,
+    ends: Vec<usize>,
+}
+
+impl<'a> AzamView<'a> {
+    /// Validate given Azam codec encoded string, and record the boundaries of its sections.
+    /// Returns an error if the string has an invalid symbol, a section with a leading zero, or an incomplete last section.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Azam codec encoded string
+    pub fn new(value: &'a str) -> Result<Self> {
+        let bytes = value.as_bytes();
+        let mut ends = Vec::<usize>::new();
+        let mut index = 0usize;
+        while index < bytes.len() {
+            let count = section_len(&bytes[index..]).map_err(|err| {
+                let offset = index + err.offset();
+                err.at(offset, ends.len()).with_input(value)
+            })?;
+            index += count;
+            ends.push(index);
+        }
+        Ok(Self { value, ends })
+    }
+
+    /// Create a view of given string and section end offsets, which must be already validated.
+    pub(crate) fn from_parts(value: &'a str, ends: Vec<usize>) -> Self {
+        Self { value, ends }
+    }
+
+    /// Returns the whole Azam codec encoded string.
+    pub fn as_str(&self) -> &'a str {
+        self.value
+    }
+
+    /// Returns the number of sections.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns true if there are no sections.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns the byte range of section at given index, or None if out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of section
+    pub fn section_range(&self, index: usize) -> Option<Range<usize>> {
+        let end = *self.ends.get(index)?;
+        let start = index.checked_sub(1).map_or(0, |prev| self.ends[prev]);
+        Some(start..end)
+    }
+
+    /// Returns the section at given index, or None if out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of section
+    pub fn section(&self, index: usize) -> Option<&'a str> {
+        self.section_range(index).map(|range| &self.value[range])
+    }
+
+    /// Returns an iterator of all sections.
+    pub fn sections(&self) -> impl ExactSizeIterator<Item = &'a str> + DoubleEndedIterator + '_ {
+        (0..self.len()).map(|index| &self.value[self.section_range(index).unwrap()])
+    }
+
+    /// Returns the first `count` sections as a single string, or all sections if there are fewer.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of sections
+    pub fn prefix(&self, count: usize) -> &'a str {
+        match count.min(self.len()) {
+            0 => "",
+            count => &self.value[..self.ends[count - 1]],
+        }
+    }
+
+    /// Compare the first `count` sections of two views as canonical strings, without decoding them.
+    /// Azam codec encoded strings sort section by section, so this is the order of composite keys by their first `count` fields.
+    /// Aliases and uppercase letters compare the same as their canonical symbols, see [`canonical_cmp`].
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - View to compare with
+    /// * `count` - Number of sections to compare
+    ///
+    /// # Examples
+    /// ```rust
+    /// use azamcodec::view::AzamView;
+    /// use std::cmp::Ordering;
+    ///
+    /// let a = AzamView::new("xytxvyyfh5wgg1").unwrap();
+    /// let b = AzamView::new("xytxvyyfh5h0").unwrap();
+    /// assert_eq!(Ordering::Equal, a.cmp_prefix(&b, 2));
+    /// assert_eq!(Ordering::Greater, a.cmp_prefix(&b, 3));
+    /// ```
+    pub fn cmp_prefix(&self, other: &AzamView<'_>, count: usize) -> Ordering {
+        canonical_cmp(self.prefix(count), other.prefix(count))
+    }
+
+    /// Decode the section at given index.
+    /// The section must be a single encoded value, and errors report the position in the whole string.
+    /// Returns an [`AzamErrorKind::Truncated`] error if index is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of section
+    #[cfg(feature = "std")]
+    pub fn decode_section<T: AzamDecode>(&self, index: usize) -> Result<T> {
+        decode_section(self.value, index, self.len(), self.section_range(index))
+    }
+}
+
+/// Decode the section of given byte range, or return an [`AzamErrorKind::Truncated`] error at the end of `value` if there is no such section.
+/// Errors report the position in the whole string.
+#[cfg(feature = "std")]
+pub(crate) fn decode_section<T: AzamDecode>(
+    value: &str,
+    index: usize,
+    len: usize,
+    range: Option<Range<usize>>,
+) -> Result<T> {
+    let range = range.ok_or_else(|| {
+        AzamError::new(AzamErrorKind::Truncated)
+            .at(value.len(), len)
+            .with_input(value)
+    })?;
+    DecodeOptions::EXACT
+        .decode_read(value[range.clone()].as_bytes())
+        .map_err(|err| {
+            let (offset, section) = (range.start + err.offset(), index + err.section());
+            err.at(offset, section).with_input(value)
+        })
+}
+
+impl<'a> TryFrom<&'a str> for AzamView<'a> {
+    type Error = AzamError;
+
+    fn try_from(value: &'a str) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl AsRef<str> for AzamView<'_> {
+    fn as_ref(&self) -> &str {
+        self.value
+    }
+}
+
+impl fmt::Display for AzamView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.value)
+    }
+}
+
+impl PartialEq for AzamView<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for AzamView<'_> {}
+
+impl PartialOrd for AzamView<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AzamView<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(other.value)
+    }
+}
+
+impl core::hash::Hash for AzamView<'_> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AzamErrorKind;
+    use crate::view::*;
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn test_azam_view() {
+        let view = AzamView::new("xytxvyyfh5wgg1").unwrap();
+        assert_eq!("xytxvyyfh5wgg1", view.as_str());
+        assert_eq!(3, view.len());
+        assert!(!view.is_empty());
+        assert_eq!(
+            vec!["xytxvyyf", "h5", "wgg1"],
+            view.sections().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["wgg1", "h5", "xytxvyyf"],
+            view.sections().rev().collect::<Vec<_>>()
+        );
+        assert_eq!(Some(8..10), view.section_range(1));
+        assert_eq!(Some("wgg1"), view.section(2));
+        assert_eq!(None, view.section(3));
+        assert_eq!("", view.prefix(0));
+        assert_eq!("xytxvyyfh5", view.prefix(2));
+        assert_eq!("xytxvyyfh5wgg1", view.prefix(5));
+
+        let view = AzamView::try_from("").unwrap();
+        assert!(view.is_empty());
+        assert_eq!(0, view.sections().len());
+    }
+
+    #[test]
+    fn test_azam_view_err() {
+        let err = AzamView::new("xytxvyyfh5w_g1").unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSymbol, err.kind());
+        assert_eq!(11, err.offset());
+        assert_eq!(2, err.section());
+        assert_eq!(Some("xytxvyyfh5w_g1"), err.input());
+        let err = AzamView::new("xytxvyyfgh5").unwrap_err();
+        assert_eq!(AzamErrorKind::LeadingZero, err.kind());
+        assert_eq!(8, err.offset());
+        let err = AzamView::new("xytxvyyfh5wgg").unwrap_err();
+        assert_eq!(AzamErrorKind::Truncated, err.kind());
+        assert_eq!(13, err.offset());
+        assert_eq!(2, err.section());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_azam_view_decode_section() {
+        let view = AzamView::new("xytxvyyfh5wgg1qzfhph0").unwrap();
+        assert_eq!(0xdeadbeefu32, view.decode_section::<u32>(0).unwrap());
+        assert_eq!(0xc001u16, view.decode_section::<u16>(2).unwrap());
+        assert_eq!(-1i8, view.decode_section::<i8>(3).unwrap());
+        assert_eq!("a", view.decode_section::<String>(4).unwrap());
+        let err = view.decode_section::<u8>(0).unwrap_err();
+        assert_eq!(AzamErrorKind::Overflow, err.kind());
+        assert_eq!(0, err.section());
+        let err = view.decode_section::<i8>(2).unwrap_err();
+        assert_eq!(AzamErrorKind::InvalidSection, err.kind());
+        assert_eq!(10, err.offset());
+        assert_eq!(2, err.section());
+        assert_eq!(Some("xytxvyyfh5wgg1qzfhph0"), err.input());
+        let err = view.decode_section::<u8>(5).unwrap_err();
+        assert_eq!(AzamErrorKind::Truncated, err.kind());
+        assert_eq!(21, err.offset());
+    }
+
+    #[test]
+    fn test_azam_view_cmp_prefix() {
+        let a = AzamView::new("xytxvyyfh5wgg1").unwrap();
+        let b = AzamView::new("xytxvyyfh5h0").unwrap();
+        let c = AzamView::new("xytxvyyf1").unwrap();
+        assert_eq!(Ordering::Equal, a.cmp_prefix(&b, 0));
+        assert_eq!(Ordering::Equal, a.cmp_prefix(&b, 2));
+        assert_eq!(Ordering::Greater, a.cmp_prefix(&b, 3));
+        assert_eq!(Ordering::Equal, a.cmp_prefix(&c, 1));
+        assert_eq!(Ordering::Less, c.cmp_prefix(&a, 2));
+        assert!(b < a);
+        assert_eq!(a, AzamView::new("xytxvyyfh5wgg1").unwrap());
+        let upper = AzamView::new("XYTXVYYFH5WGGL").unwrap();
+        assert_eq!(Ordering::Equal, a.cmp_prefix(&upper, 3));
+        assert_eq!(Ordering::Greater, upper.cmp_prefix(&b, 3));
+        assert_ne!(a, upper);
+    }
+}